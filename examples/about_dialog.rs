@@ -0,0 +1,22 @@
+const LICENSE: &str = "\
+MIT License
+
+Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the \"Software\"), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED.
+";
+
+fn main() {
+	rustydialogs::AboutDialog {
+		name: "Rusty Dialogs",
+		version: env!("CARGO_PKG_VERSION"),
+		icon: "dialog-information",
+		comments: "Provides a simple and cross-platform way to display native dialog boxes.",
+		license: LICENSE,
+		authors: &["Casper <CasualX@users.noreply.github.com>"],
+		website: "https://github.com/CasualX/rustydialogs",
+		owner: None,
+	}.show();
+}
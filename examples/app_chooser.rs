@@ -0,0 +1,18 @@
+fn main() {
+	let path = std::env::args_os().nth(1).unwrap_or_else(|| "readme.md".into());
+
+	let chooser = rustydialogs::AppChooser {
+		title: "Open With",
+		path: std::path::Path::new(&path),
+		mime_type: None,
+		owner: None,
+	};
+
+	match chooser.show() {
+		Some(app) => println!("Chosen application: {app}"),
+		None => println!("No application chosen"),
+	}
+
+	let launched = chooser.open();
+	println!("Launched: {launched}");
+}
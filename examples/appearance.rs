@@ -0,0 +1,25 @@
+fn main() {
+	let appearance = rustydialogs::appearance();
+
+	println!("Color scheme: {:?}", appearance.color_scheme);
+	match appearance.accent_color {
+		Some(color) => println!("Accent color: {color}"),
+		None => println!("Accent color: (not set)"),
+	}
+	println!("High contrast: {}", appearance.high_contrast);
+
+	// Show a dialog in the opposite color scheme.
+	let color_scheme = match appearance.color_scheme {
+		rustydialogs::ColorScheme::Dark => rustydialogs::ColorScheme::Light,
+		_ => rustydialogs::ColorScheme::Dark,
+	};
+	rustydialogs::set_color_scheme(color_scheme);
+
+	rustydialogs::MessageBox {
+		title: "Appearance",
+		message: &format!("This dialog is forced to {color_scheme:?}."),
+		icon: rustydialogs::MessageIcon::Info,
+		buttons: rustydialogs::MessageButtons::Ok,
+		owner: None,
+	}.show();
+}
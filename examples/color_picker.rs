@@ -5,14 +5,22 @@ fn main() {
 			red: 0xFF,
 			green: 0x00,
 			blue: 0x77,
+			alpha: 0xCC,
 		},
+		alpha: true,
+		palette: &[
+			rustydialogs::ColorValue { red: 0xFF, green: 0x00, blue: 0x77, alpha: 0xFF },
+			rustydialogs::ColorValue { red: 0x00, green: 0x77, blue: 0xFF, alpha: 0xFF },
+			rustydialogs::ColorValue { red: 0x77, green: 0xFF, blue: 0x00, alpha: 0xFF },
+		],
+		key: Some("color_picker"),
 		owner: None,
 	};
 
 	match picker.show() {
 		Some(color) => println!(
-			"Selected color: #{:02X}{:02X}{:02X}",
-			color.red, color.green, color.blue
+			"Selected color: #{:02X}{:02X}{:02X}{:02X}",
+			color.red, color.green, color.blue, color.alpha
 		),
 		None => println!("Color selection canceled"),
 	}
@@ -4,8 +4,7 @@ fn main() {
 	let dialog = rustydialogs::FileDialog {
 		title: "Select folders",
 		path: current_dir.as_deref(),
-		filters: None,
-		owner: None,
+		..Default::default()
 	};
 
 	match dialog.choose_folders() {
@@ -0,0 +1,15 @@
+fn main() {
+	let picker = rustydialogs::FontPicker {
+		title: "Pick a font",
+		value: "Monospace Bold 11",
+		owner: None,
+	};
+
+	match picker.show() {
+		Some(font) => println!(
+			"Selected font: {font} (family: {}, style: {:?}, weight: {}, size: {})",
+			font.family, font.style, font.weight, font.size
+		),
+		None => println!("Font selection canceled"),
+	}
+}
@@ -0,0 +1,14 @@
+fn main() {
+	let picker = rustydialogs::IconPicker {
+		title: "Choose an icon",
+		value: "utilities-terminal",
+		context: rustydialogs::IconContext::Application,
+		owner: None,
+	};
+
+	match picker.show() {
+		Some(rustydialogs::IconValue::Name(name)) => println!("Themed icon: {name}"),
+		Some(rustydialogs::IconValue::File(path)) => println!("Icon file: {}", path.display()),
+		None => println!("Icon selection canceled"),
+	}
+}
@@ -0,0 +1,15 @@
+use std::{thread, time};
+
+fn main() {
+	let mut log = rustydialogs::LogWindow::new("Installing...");
+
+	for i in 1..=20 {
+		log.append_line(&format!("Installing package {i} of 20"));
+		thread::sleep(time::Duration::from_millis(250));
+	}
+
+	log.append_line("Done.");
+	log.set_title("Installation complete");
+	log.finish();
+	log.wait_closed();
+}
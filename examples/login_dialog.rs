@@ -0,0 +1,13 @@
+fn main() {
+	let dialog = rustydialogs::LoginDialog {
+		title: "Login",
+		message: "Enter your credentials:",
+		username: "admin",
+		owner: None,
+	};
+
+	match dialog.show() {
+		Some(credentials) => println!("Logged in as {} ({} characters password)", credentials.username, credentials.password.chars().count()),
+		None => println!("Login canceled"),
+	}
+}
@@ -5,14 +5,22 @@ fn main() {
 		rustydialogs::FileFilter {
 			name: "Markdown Files",
 			patterns: &["*.md"],
+			mime_types: &[],
 		},
 		rustydialogs::FileFilter {
 			name: "Text Files",
 			patterns: &["*.txt"],
+			mime_types: &[],
 		},
 		rustydialogs::FileFilter {
 			name: "JSON Files",
 			patterns: &["*.json"],
+			mime_types: &[],
+		},
+		rustydialogs::FileFilter {
+			name: "Images",
+			patterns: &[],
+			mime_types: &["image/*"],
 		},
 	];
 
@@ -20,7 +28,7 @@ fn main() {
 		title: "Open file(s)",
 		path: Some(Path::new("readme.md")),
 		filters: Some(&filters),
-		owner: None,
+		..Default::default()
 	};
 
 	match dialog.pick_files() {
@@ -0,0 +1,21 @@
+fn main() {
+	let dialog = rustydialogs::PrintDialog {
+		title: "Print document",
+		owner: None,
+	};
+
+	match dialog.show() {
+		Some(settings) => {
+			println!(
+				"Printer: {:?}, copies: {}, pages: {:?}, paper: {} ({}x{} mm), orientation: {:?}",
+				settings.printer, settings.copies, settings.page_ranges,
+				settings.paper, settings.paper_width, settings.paper_height, settings.orientation
+			);
+			if let Some(path) = std::env::args_os().nth(1) {
+				let printed = settings.print_file("Document", std::path::Path::new(&path));
+				println!("Printed {}: {printed}", path.to_string_lossy());
+			}
+		}
+		None => println!("Print canceled"),
+	}
+}
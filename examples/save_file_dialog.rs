@@ -5,10 +5,27 @@ fn main() {
 		rustydialogs::FileFilter {
 			name: "Text Files",
 			patterns: &["*.txt"],
+			mime_types: &[],
 		},
 		rustydialogs::FileFilter {
 			name: "JSON Files",
 			patterns: &["*.json"],
+			mime_types: &[],
+		},
+	];
+
+	let choices = [
+		rustydialogs::FileChoice {
+			id: "encoding",
+			label: "Encoding",
+			options: &[("utf8", "UTF-8"), ("latin1", "Latin-1")],
+			default: "utf8",
+		},
+		rustydialogs::FileChoice {
+			id: "metadata",
+			label: "Include metadata",
+			options: &[],
+			default: "false",
 		},
 	];
 
@@ -16,11 +33,22 @@ fn main() {
 		title: "Save a file",
 		path: Some(Path::new("output.txt")),
 		filters: Some(&filters),
-		owner: None,
+		default_extension: Some("txt"),
+		accept_label: Some("Export"),
+		choices: &choices,
+		..Default::default()
 	};
 
-	match dialog.save_file() {
-		Some(path) => println!("Save path: {}", path.display()),
+	match dialog.save_file_with_selection() {
+		Some((path, selection)) => {
+			match selection.filter {
+				Some(filter) => println!("Save path: {} ({})", path.display(), filters[filter].name),
+				None => println!("Save path: {}", path.display()),
+			}
+			for (id, option) in &selection.choices {
+				println!("  {id}: {option}");
+			}
+		}
 		None => println!("Save canceled"),
 	}
 }
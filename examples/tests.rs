@@ -9,7 +9,15 @@ enum TestSelector {
 	OpenFileDialog,
 	FolderDialog,
 	ColorPicker,
+	FontPicker,
 	TextInput,
+	LoginDialog,
+	TextViewer,
+	AboutDialog,
+	LogWindow,
+	PrintDialog,
+	AppChooser,
+	IconPicker,
 	Notification,
 }
 
@@ -36,7 +44,15 @@ fn main() {
 			test_open_file_dialog();
 			test_folder_dialog();
 			test_color_picker();
+			test_font_picker();
 			test_text_input();
+			test_login_dialog();
+			test_text_viewer();
+			test_about_dialog();
+			test_log_window();
+			test_print_dialog();
+			test_app_chooser();
+			test_icon_picker();
 			test_notification();
 		}
 		TestSelector::MessageBox => test_message_box(),
@@ -44,7 +60,15 @@ fn main() {
 		TestSelector::OpenFileDialog => test_open_file_dialog(),
 		TestSelector::FolderDialog => test_folder_dialog(),
 		TestSelector::ColorPicker => test_color_picker(),
+		TestSelector::FontPicker => test_font_picker(),
 		TestSelector::TextInput => test_text_input(),
+		TestSelector::LoginDialog => test_login_dialog(),
+		TestSelector::TextViewer => test_text_viewer(),
+		TestSelector::AboutDialog => test_about_dialog(),
+		TestSelector::LogWindow => test_log_window(),
+		TestSelector::PrintDialog => test_print_dialog(),
+		TestSelector::AppChooser => test_app_chooser(),
+		TestSelector::IconPicker => test_icon_picker(),
 		TestSelector::Notification => test_notification(),
 	}
 }
@@ -77,6 +101,14 @@ fn prompt_selector() -> TestSelector {
 	println!("  f = FolderDialog");
 	println!("  t = TextInput");
 	println!("  c = ColorPicker");
+	println!("  p = FontPicker");
+	println!("  l = LoginDialog");
+	println!("  v = TextViewer");
+	println!("  a = AboutDialog");
+	println!("  w = LogWindow");
+	println!("  r = PrintDialog");
+	println!("  h = AppChooser");
+	println!("  i = IconPicker");
 	println!("  n = Notification");
 
 	loop {
@@ -106,7 +138,15 @@ fn parse_selector(s: &str) -> Option<TestSelector> {
 		"o" => Some(TestSelector::OpenFileDialog),
 		"f" => Some(TestSelector::FolderDialog),
 		"c" => Some(TestSelector::ColorPicker),
+		"p" => Some(TestSelector::FontPicker),
 		"t" => Some(TestSelector::TextInput),
+		"l" => Some(TestSelector::LoginDialog),
+		"v" => Some(TestSelector::TextViewer),
+		"a" => Some(TestSelector::AboutDialog),
+		"w" => Some(TestSelector::LogWindow),
+		"r" => Some(TestSelector::PrintDialog),
+		"h" => Some(TestSelector::AppChooser),
+		"i" => Some(TestSelector::IconPicker),
 		"n" => Some(TestSelector::Notification),
 		_ => None,
 	}
@@ -192,16 +232,98 @@ fn test_save_file_dialog() {
 				rustydialogs::FileFilter {
 					name: "Markdown Files",
 					patterns: &["*.md"],
+					mime_types: &[],
 				},
 				rustydialogs::FileFilter {
 					name: "Text Files",
 					patterns: &["*.txt"],
+					mime_types: &[],
 				},
 			]),
-			owner: None,
+			..Default::default()
+		}.save_file()
+	);
+
+	step("Select `readme.md` and press Save, the dialog does not ask to replace it.",
+		Some(current_dir.join("readme.md")),
+		|| rustydialogs::FileDialog {
+			title: "[tests] SaveFileDialog (no overwrite confirmation)",
+			path: Some(&current_dir),
+			confirm_overwrite: false,
+			..Default::default()
 		}.save_file()
 	);
 
+	step("Type `notes` as the file name, select the `JSON Files` filter and press Save.",
+		Some((current_dir.join("notes.json"), Some(1))),
+		|| rustydialogs::FileDialog {
+			title: "[tests] SaveFileDialog (default extension)",
+			path: Some(&current_dir),
+			filters: Some(&[
+				rustydialogs::FileFilter {
+					name: "Text Files",
+					patterns: &["*.txt"],
+					mime_types: &[],
+				},
+				rustydialogs::FileFilter {
+					name: "JSON Files",
+					patterns: &["*.json"],
+					mime_types: &[],
+				},
+			]),
+			default_extension: Some("txt"),
+			..Default::default()
+		}.save_file_with_selection().map(|(path, selection)| (path, selection.filter))
+	);
+
+	step("Select the `Latin-1` encoding, check `Include metadata` and save as `export.txt`.",
+		Some((current_dir.join("export.txt"), Some("latin1".to_string()), Some("true".to_string()))),
+		|| rustydialogs::FileDialog {
+			title: "[tests] SaveFileDialog (choices)",
+			path: Some(&current_dir),
+			choices: &[
+				rustydialogs::FileChoice {
+					id: "encoding",
+					label: "Encoding",
+					options: &[("utf8", "UTF-8"), ("latin1", "Latin-1")],
+					default: "utf8",
+				},
+				rustydialogs::FileChoice {
+					id: "metadata",
+					label: "Include metadata",
+					options: &[],
+					default: "false",
+				},
+			],
+			..Default::default()
+		}.save_file_with_selection().map(|(path, selection)| {
+			(path, selection.choice("encoding").map(String::from), selection.choice("metadata").map(String::from))
+		})
+	);
+
+	let remembered = |title| rustydialogs::FileDialog {
+		title,
+		key: Some("tests-save"),
+		..Default::default()
+	};
+	step("Navigate to the `examples` folder, type `remembered.txt` as the file name and press Save.",
+		Some(current_dir.join("examples").join("remembered.txt")),
+		|| remembered("[tests] SaveFileDialog (remember directory)").save_file()
+	);
+	step("The dialog starts in the `examples` folder with an empty file name, type `remembered.txt` and press Save.",
+		Some(current_dir.join("examples").join("remembered.txt")),
+		|| remembered("[tests] SaveFileDialog (remembered directory)").save_file()
+	);
+
+	step("Select the `examples` folder and press Save or Select.",
+		Some(vec![current_dir.join("examples").join("export-1.txt"), current_dir.join("examples").join("export-2.txt")]),
+		|| rustydialogs::FileDialog {
+			title: "[tests] SaveFileDialog (multiple files)",
+			path: Some(&current_dir),
+			..Default::default()
+		}.save_files(&["export-1.txt", "export-2.txt"])
+	);
+
 	step("Dismiss the dialog.",
 		None,
 		|| rustydialogs::FileDialog {
@@ -211,9 +333,10 @@ fn test_save_file_dialog() {
 				rustydialogs::FileFilter {
 					name: "Text Files",
 					patterns: &["*.txt"],
+					mime_types: &[],
 				},
 			]),
-			owner: None,
+			..Default::default()
 		}.save_file()
 	);
 }
@@ -232,9 +355,112 @@ fn test_open_file_dialog() {
 				rustydialogs::FileFilter {
 					name: "TOML Files",
 					patterns: &["*.toml"],
+					mime_types: &[],
 				},
 			]),
-			owner: None,
+			..Default::default()
+		}.pick_file()
+	);
+
+	step("Select `Cargo.toml` and press Open.",
+		Some(format!("file://{}/Cargo.toml", current_dir.display())),
+		|| rustydialogs::FileDialog {
+			title: "[tests] OpenFileDialog (URI)",
+			path: Some(&current_dir),
+			local_only: false,
+			..Default::default()
+		}.pick_uri()
+	);
+
+	let remembered = |title| rustydialogs::FileDialog {
+		title,
+		key: Some("tests-open"),
+		..Default::default()
+	};
+	step("Navigate to the `src` folder, select `lib.rs` and press Open.",
+		Some(current_dir.join("src").join("lib.rs")),
+		|| remembered("[tests] OpenFileDialog (remember directory)").pick_file()
+	);
+	step("The dialog starts in the `src` folder, select `utils.rs` and press Open.",
+		Some(current_dir.join("src").join("utils.rs")),
+		|| remembered("[tests] OpenFileDialog (remembered directory)").pick_file()
+	);
+
+	step("The `TOML Files` filter is selected and there is no `All Files` filter, select `Cargo.toml` and press Open.",
+		Some((current_dir.join("Cargo.toml"), Some(1))),
+		|| rustydialogs::FileDialog {
+			title: "[tests] OpenFileDialog (default filter)",
+			path: Some(&current_dir),
+			filters: Some(&[
+				rustydialogs::FileFilter {
+					name: "Markdown Files",
+					patterns: &["*.md"],
+					mime_types: &[],
+				},
+				rustydialogs::FileFilter {
+					name: "TOML Files",
+					patterns: &["*.toml"],
+					mime_types: &[],
+				},
+			]),
+			default_filter: 1,
+			all_files: false,
+			..Default::default()
+		}.pick_file_with_selection().map(|(path, selection)| (path, selection.filter))
+	);
+
+	step("Only the Cargo manifest, lock file and Markdown files are shown, select `readme.md` and press Open.",
+		Some(current_dir.join("readme.md")),
+		|| rustydialogs::FileDialog {
+			title: "[tests] OpenFileDialog (brace pattern)",
+			path: Some(&current_dir),
+			filters: Some(&[
+				rustydialogs::FileFilter {
+					name: "Cargo manifest and Markdown Files",
+					patterns: &["CARGO.{TOML,lock}", "*.MD"],
+					mime_types: &[],
+				},
+			]),
+			all_files: false,
+			..Default::default()
+		}.pick_file()
+	);
+
+	step("Only Markdown files are shown, select `readme.md` and press Open.",
+		Some(current_dir.join("readme.md")),
+		|| rustydialogs::FileDialog {
+			title: "[tests] OpenFileDialog (MIME type filter)",
+			path: Some(&current_dir),
+			filters: Some(&[
+				rustydialogs::FileFilter {
+					name: "Markdown Files",
+					patterns: &[],
+					mime_types: &["text/markdown"],
+				},
+			]),
+			all_files: false,
+			..Default::default()
+		}.pick_file()
+	);
+
+	step("The buttons are labeled `Import` and `Back` where supported, select `Cargo.toml` and press Import.",
+		Some(current_dir.join("Cargo.toml")),
+		|| rustydialogs::FileDialog {
+			title: "[tests] OpenFileDialog (button labels)",
+			path: Some(&current_dir),
+			accept_label: Some("Import"),
+			cancel_label: Some("Back"),
+			..Default::default()
+		}.pick_file()
+	);
+
+	step("Hidden files are shown, select `.gitignore` and press Open.",
+		Some(current_dir.join(".gitignore")),
+		|| rustydialogs::FileDialog {
+			title: "[tests] OpenFileDialog (show hidden)",
+			path: Some(&current_dir),
+			show_hidden: true,
+			..Default::default()
 		}.pick_file()
 	);
 
@@ -246,8 +472,7 @@ fn test_open_file_dialog() {
 		|| rustydialogs::FileDialog {
 			title: "[tests] OpenFileDialog (multiple)",
 			path: Some(&current_dir),
-			filters: None,
-			owner: None,
+			..Default::default()
 		}.pick_files().map(sorted)
 	);
 
@@ -260,9 +485,10 @@ fn test_open_file_dialog() {
 				rustydialogs::FileFilter {
 					name: "TOML Files",
 					patterns: &["*.toml"],
+					mime_types: &[],
 				},
 			]),
-			owner: None,
+			..Default::default()
 		}.pick_file()
 	);
 }
@@ -277,8 +503,7 @@ fn test_folder_dialog() {
 		|| rustydialogs::FileDialog {
 			title: "[tests] FileDialog choose_folder",
 			path: Some(&current_dir),
-			filters: None,
-			owner: None,
+			..Default::default()
 		}.choose_folder()
 	);
 
@@ -290,8 +515,7 @@ fn test_folder_dialog() {
 		|| rustydialogs::FileDialog {
 			title: "[tests] FileDialog choose_folders",
 			path: Some(&current_dir),
-			filters: None,
-			owner: None,
+			..Default::default()
 		}.choose_folders().map(sorted)
 	);
 
@@ -300,8 +524,7 @@ fn test_folder_dialog() {
 		|| rustydialogs::FileDialog {
 			title: "[tests] Dismiss FileDialog choose_folder",
 			path: Some(&current_dir),
-			filters: None,
-			owner: None,
+			..Default::default()
 		}.choose_folder()
 	);
 }
@@ -310,28 +533,104 @@ fn test_color_picker() {
 	println!("\n{}", Color("==== Testing ColorPicker ====", "120;190;255"));
 
 	step("Select pure RED (#FF0000) and press OK.",
-		Some(rustydialogs::ColorValue { red: 255, green: 0, blue: 0 }),
+		Some(rustydialogs::ColorValue { red: 255, green: 0, blue: 0, alpha: 255 }),
 		|| rustydialogs::ColorPicker {
 			title: "[tests] ColorPicker",
-			value: rustydialogs::ColorValue { red: 255, green: 0, blue: 0 },
+			value: rustydialogs::ColorValue { red: 255, green: 0, blue: 0, alpha: 255 },
+			alpha: false,
+			palette: &[],
+			key: None,
 			owner: None,
 		}.show()
 	);
 
 	step("Select specific color (#4FB3A3) (79, 179, 163) and press OK.",
-		Some(rustydialogs::ColorValue { red: 79, green: 179, blue: 163 }),
+		Some(rustydialogs::ColorValue { red: 79, green: 179, blue: 163, alpha: 255 }),
 		|| rustydialogs::ColorPicker {
 			title: "[tests] ColorPicker",
-			value: rustydialogs::ColorValue { red: 255, green: 0, blue: 0 },
+			value: rustydialogs::ColorValue { red: 255, green: 0, blue: 0, alpha: 255 },
+			alpha: false,
+			palette: &[],
+			key: None,
 			owner: None,
 		}.show()
 	);
 
+	step("Set the opacity to 50% and press OK.",
+		Some(128),
+		|| rustydialogs::ColorPicker {
+			title: "[tests] ColorPicker: 50% opacity",
+			value: rustydialogs::ColorValue { red: 255, green: 0, blue: 0, alpha: 255 },
+			alpha: true,
+			palette: &[],
+			key: None,
+			owner: None,
+		}.show().map(|color| color.alpha)
+	);
+
+	step("Select the blue (#3366CC) custom color and press OK.",
+		Some(rustydialogs::ColorValue { red: 0x33, green: 0x66, blue: 0xCC, alpha: 255 }),
+		|| rustydialogs::ColorPicker {
+			title: "[tests] ColorPicker: custom palette",
+			value: rustydialogs::ColorValue { red: 255, green: 0, blue: 0, alpha: 255 },
+			alpha: false,
+			palette: &[
+				rustydialogs::ColorValue { red: 0xCC, green: 0x33, blue: 0x33, alpha: 255 },
+				rustydialogs::ColorValue { red: 0x33, green: 0xCC, blue: 0x33, alpha: 255 },
+				rustydialogs::ColorValue { red: 0x33, green: 0x66, blue: 0xCC, alpha: 255 },
+			],
+			key: Some("tests"),
+			owner: None,
+		}.show()
+	);
+
+	step("Click anywhere on the screen to pick a color (Linux with the xdg-portal feature only).",
+		true,
+		|| rustydialogs::ColorPicker {
+			title: "[tests] ColorPicker: pick from screen",
+			value: rustydialogs::ColorValue { red: 255, green: 0, blue: 0, alpha: 255 },
+			alpha: false,
+			palette: &[],
+			key: None,
+			owner: None,
+		}.pick_from_screen().is_some()
+	);
+
 	step("Dismiss the dialog.",
 		None,
 		|| rustydialogs::ColorPicker {
 			title: "[tests] Dismiss ColorPicker",
-			value: rustydialogs::ColorValue { red: 255, green: 0, blue: 0 },
+			value: rustydialogs::ColorValue { red: 255, green: 0, blue: 0, alpha: 255 },
+			alpha: false,
+			palette: &[],
+			key: None,
+			owner: None,
+		}.show()
+	);
+}
+
+fn test_font_picker() {
+	println!("\n{}", Color("==== Testing FontPicker ====", "120;190;255"));
+
+	step("Keep the initial font (Serif Bold Italic 14) and press OK.",
+		Some(rustydialogs::FontValue {
+			family: String::from("Serif"),
+			style: rustydialogs::FontStyle::Italic,
+			weight: 700,
+			size: 14.0,
+		}),
+		|| rustydialogs::FontPicker {
+			title: "[tests] FontPicker",
+			value: "Serif Bold Italic 14",
+			owner: None,
+		}.show()
+	);
+
+	step("Dismiss the dialog.",
+		None,
+		|| rustydialogs::FontPicker {
+			title: "[tests] Dismiss FontPicker",
+			value: "Serif 14",
 			owner: None,
 		}.show()
 	);
@@ -385,6 +684,191 @@ fn test_text_input() {
 	);
 }
 
+fn test_login_dialog() {
+	println!("\n{}", Color("==== Testing LoginDialog ====", "120;190;255"));
+
+	step("Enter user `rusty` with password `Password123` and press OK.",
+		Some(rustydialogs::Credentials {
+			username: String::from("rusty"),
+			password: String::from("Password123"),
+		}),
+		|| rustydialogs::LoginDialog {
+			title: "[tests] LoginDialog",
+			message: "Instruction: Enter user `rusty` with password `Password123` and press OK.",
+			username: "",
+			owner: None,
+		}.show()
+	);
+
+	step("Dismiss the dialog.",
+		None,
+		|| rustydialogs::LoginDialog {
+			title: "[tests] Dismiss LoginDialog",
+			message: "Instruction: Dismiss the dialog (e.g. by pressing Esc or clicking the close button).",
+			username: "rusty",
+			owner: None,
+		}.show()
+	);
+}
+
+fn test_text_viewer() {
+	println!("\n{}", Color("==== Testing TextViewer ====", "120;190;255"));
+
+	step("Read the text and press OK.",
+		true,
+		|| rustydialogs::TextViewer {
+			title: "[tests] TextViewer",
+			content: rustydialogs::TextContent::Text("Instruction: Read this text and press OK.\n\nLorem ipsum dolor sit amet, consectetur adipiscing elit."),
+			checkbox: None,
+			owner: None,
+		}.show()
+	);
+
+	step("Check the checkbox and press OK.",
+		true,
+		|| rustydialogs::TextViewer {
+			title: "[tests] TextViewer with checkbox",
+			content: rustydialogs::TextContent::Text("Instruction: Check the checkbox and press OK."),
+			checkbox: Some("I agree"),
+			owner: None,
+		}.show()
+	);
+
+	step("Dismiss the dialog.",
+		false,
+		|| rustydialogs::TextViewer {
+			title: "[tests] Dismiss TextViewer",
+			content: rustydialogs::TextContent::Text("Instruction: Dismiss the dialog (e.g. by pressing Esc or clicking the close button)."),
+			checkbox: Some("I agree"),
+			owner: None,
+		}.show()
+	);
+}
+
+fn test_about_dialog() {
+	println!("\n{}", Color("==== Testing AboutDialog ====", "120;190;255"));
+
+	step("Open the license, close it, then close the about dialog. Confirm all information was shown.",
+		true,
+		|| {
+			rustydialogs::AboutDialog {
+				name: "[tests] AboutDialog",
+				version: "1.2.3",
+				icon: "dialog-information",
+				comments: "Instruction: Open the license, close it, then close this dialog.",
+				license: "Test license text.",
+				authors: &["Alice <alice@example.com>", "Bob <bob@example.com>"],
+				website: "https://example.com",
+				owner: None,
+			}.show();
+			confirm("  Were the name, version, comments, authors, website and license shown? [Y/n]: ", true)
+		}
+	);
+}
+
+fn test_log_window() {
+	println!("\n{}", Color("==== Testing LogWindow ====", "120;190;255"));
+
+	step("Watch the lines appear, then close the window once the Close button activates.",
+		true,
+		|| {
+			let mut log = rustydialogs::LogWindow::new("[tests] LogWindow (running)");
+			for i in 1..=10 {
+				log.append_line(&format!("Instruction: Wait for the job to finish, line {i} of 10"));
+				std::thread::sleep(std::time::Duration::from_millis(300));
+			}
+			log.append_line("Instruction: Close the window.");
+			log.set_title("[tests] LogWindow (finished)");
+			log.wait_closed();
+			confirm("  Were all lines shown and did the title change? [Y/n]: ", true)
+		}
+	);
+}
+
+fn test_print_dialog() {
+	println!("\n{}", Color("==== Testing PrintDialog ====", "120;190;255"));
+
+	step("Set 2 copies, landscape orientation and press Print.",
+		Some((2, rustydialogs::PageOrientation::Landscape)),
+		|| rustydialogs::PrintDialog {
+			title: "[tests] PrintDialog: 2 copies, landscape",
+			owner: None,
+		}.show().map(|settings| (settings.copies, settings.orientation))
+	);
+
+	step("Dismiss the dialog.",
+		None,
+		|| rustydialogs::PrintDialog {
+			title: "[tests] Dismiss PrintDialog",
+			owner: None,
+		}.show()
+	);
+}
+
+fn test_app_chooser() {
+	println!("\n{}", Color("==== Testing AppChooser ====", "120;190;255"));
+
+	step("Choose a text editor and press OK.",
+		true,
+		|| {
+			let app = rustydialogs::AppChooser {
+				title: "[tests] AppChooser: choose a text editor",
+				path: std::path::Path::new("readme.md"),
+				mime_type: Some("text/plain"),
+				owner: None,
+			}.show();
+			println!("  Chosen application: {app:?}");
+			app.is_some() && confirm("  Is this the text editor you chose? [Y/n]: ", true)
+		}
+	);
+
+	step("Dismiss the dialog.",
+		None,
+		|| rustydialogs::AppChooser {
+			title: "[tests] Dismiss AppChooser",
+			path: std::path::Path::new("readme.md"),
+			mime_type: None,
+			owner: None,
+		}.show()
+	);
+
+	step("Choose a text editor to open the readme.",
+		true,
+		|| {
+			rustydialogs::AppChooser {
+				title: "[tests] AppChooser: open the readme",
+				path: std::path::Path::new("readme.md"),
+				mime_type: None,
+				owner: None,
+			}.open() && confirm("  Did the readme open in the chosen editor? [Y/n]: ", true)
+		}
+	);
+}
+
+fn test_icon_picker() {
+	println!("\n{}", Color("==== Testing IconPicker ====", "120;190;255"));
+
+	step("Pick the `folder` icon and press OK.",
+		Some(rustydialogs::IconValue::Name(String::from("folder"))),
+		|| rustydialogs::IconPicker {
+			title: "[tests] IconPicker: pick `folder`",
+			value: "folder",
+			context: rustydialogs::IconContext::Place,
+			owner: None,
+		}.show()
+	);
+
+	step("Dismiss the dialog.",
+		None,
+		|| rustydialogs::IconPicker {
+			title: "[tests] Dismiss IconPicker",
+			value: "",
+			context: rustydialogs::IconContext::Any,
+			owner: None,
+		}.show()
+	);
+}
+
 fn test_notification() {
 	println!("\n{}", Color("==== Testing Notification ====", "120;190;255"));
 
@@ -0,0 +1,17 @@
+use std::path::Path;
+
+fn main() {
+	let viewer = rustydialogs::TextViewer {
+		title: "License Agreement",
+		content: rustydialogs::TextContent::File(Path::new("Cargo.toml")),
+		checkbox: Some("I have read and agree to the terms"),
+		owner: None,
+	};
+
+	if viewer.show() {
+		println!("Accepted");
+	}
+	else {
+		println!("Declined");
+	}
+}
@@ -78,10 +78,12 @@ impl App {
 			rustydialogs::FileFilter {
 				name: "Rust Sources",
 				patterns: &["*.rs"],
+				mime_types: &[],
 			},
 			rustydialogs::FileFilter {
 				name: "TOML Files",
 				patterns: &["*.toml"],
+				mime_types: &[],
 			},
 		];
 		let result = rustydialogs::FileDialog {
@@ -89,6 +91,7 @@ impl App {
 			path: Some(&self.current_dir),
 			filters: Some(&filters),
 			owner: self.owner(),
+			..Default::default()
 		}.pick_file();
 		self.set_status(&format!("open file -> {result:?}"));
 	}
@@ -97,8 +100,8 @@ impl App {
 		let result = rustydialogs::FileDialog {
 			title: "Open multiple files from the winit example",
 			path: Some(&self.current_dir),
-			filters: None,
 			owner: self.owner(),
+			..Default::default()
 		}.pick_files();
 		self.set_status(&format!("open files -> {result:?}"));
 	}
@@ -109,10 +112,12 @@ impl App {
 			rustydialogs::FileFilter {
 				name: "Text Files",
 				patterns: &["*.txt"],
+				mime_types: &[],
 			},
 			rustydialogs::FileFilter {
 				name: "Markdown Files",
 				patterns: &["*.md"],
+				mime_types: &[],
 			},
 		];
 		let result = rustydialogs::FileDialog {
@@ -120,6 +125,7 @@ impl App {
 			path: Some(default_path.as_path()),
 			filters: Some(&filters),
 			owner: self.owner(),
+			..Default::default()
 		}.save_file();
 		self.set_status(&format!("save file -> {result:?}"));
 	}
@@ -128,8 +134,8 @@ impl App {
 		let result = rustydialogs::FileDialog {
 			title: "Choose a folder from the winit example",
 			path: Some(&self.current_dir),
-			filters: None,
 			owner: self.owner(),
+			..Default::default()
 		}.choose_folder();
 		self.set_status(&format!("choose folder -> {result:?}"));
 	}
@@ -138,8 +144,8 @@ impl App {
 		let result = rustydialogs::FileDialog {
 			title: "Choose multiple folders from the winit example",
 			path: Some(&self.current_dir),
-			filters: None,
 			owner: self.owner(),
+			..Default::default()
 		}.choose_folders();
 		self.set_status(&format!("choose folders -> {result:?}"));
 	}
@@ -151,7 +157,11 @@ impl App {
 				red: 79,
 				green: 179,
 				blue: 163,
+				alpha: 255,
 			},
+			alpha: false,
+			palette: &[],
+			key: Some("winit"),
 			owner: self.owner(),
 		}.show();
 		self.set_status(&format!("color picker -> {result:?}"));
@@ -0,0 +1,417 @@
+//! Color values and conversions.
+//!
+//! [`ColorValue`] parses from and formats to CSS color strings:
+//!
+//! ```
+//! use rustydialogs::ColorValue;
+//!
+//! let color: ColorValue = "#4FB3A3".parse().unwrap();
+//! assert_eq!(color, ColorValue::rgb(79, 179, 163));
+//! assert_eq!("rgba(79, 179, 163, 0.5)".parse::<ColorValue>().unwrap().alpha, 128);
+//! assert_eq!("rebeccapurple".parse::<ColorValue>().unwrap(), ColorValue::rgb(0x66, 0x33, 0x99));
+//!
+//! assert_eq!(color.to_string(), "#4FB3A3");
+//! assert_eq!(format!("{:#}", color), "rgb(79, 179, 163)");
+//!
+//! let hsl = color.to_hsl();
+//! assert_eq!(ColorValue::from_hsl(hsl), color);
+//! ```
+
+use std::{error, fmt};
+use std::str::FromStr;
+
+/// Color value.
+///
+/// Parses from CSS color strings with [`str::parse`]:
+/// `#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa`, `rgb()`, `rgba()`, `hsl()`, `hsla()` and the named CSS colors.
+///
+/// Formats as `#RRGGBB`, or `#RRGGBBAA` if not fully opaque.
+/// The alternate flag (`{:#}`) formats as `rgb(r, g, b)` or `rgba(r, g, b, a)` instead.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ColorValue {
+	/// The red component of the color, in the range [0, 255].
+	pub red: u8,
+	/// The green component of the color, in the range [0, 255].
+	pub green: u8,
+	/// The blue component of the color, in the range [0, 255].
+	pub blue: u8,
+	/// The alpha component of the color, in the range [0, 255] where 255 is fully opaque.
+	pub alpha: u8,
+}
+
+/// Color in the HSV (hue, saturation, value) color model.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Hsv {
+	/// The hue in degrees, in the range [0, 360).
+	pub hue: f64,
+	/// The saturation, in the range [0, 1].
+	pub saturation: f64,
+	/// The value, in the range [0, 1].
+	pub value: f64,
+	/// The alpha, in the range [0, 1] where 1 is fully opaque.
+	pub alpha: f64,
+}
+
+/// Color in the HSL (hue, saturation, lightness) color model.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Hsl {
+	/// The hue in degrees, in the range [0, 360).
+	pub hue: f64,
+	/// The saturation, in the range [0, 1].
+	pub saturation: f64,
+	/// The lightness, in the range [0, 1].
+	pub lightness: f64,
+	/// The alpha, in the range [0, 1] where 1 is fully opaque.
+	pub alpha: f64,
+}
+
+/// Error returned when parsing an invalid color string.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ParseColorError;
+
+impl fmt::Display for ParseColorError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("invalid color")
+	}
+}
+
+impl error::Error for ParseColorError {}
+
+impl ColorValue {
+	/// Fully opaque color from its red, green and blue components.
+	#[inline]
+	pub const fn rgb(red: u8, green: u8, blue: u8) -> ColorValue {
+		ColorValue { red, green, blue, alpha: 255 }
+	}
+
+	/// Color from its red, green, blue and alpha components.
+	#[inline]
+	pub const fn rgba(red: u8, green: u8, blue: u8, alpha: u8) -> ColorValue {
+		ColorValue { red, green, blue, alpha }
+	}
+
+	/// Converts the color to the HSV color model.
+	pub fn to_hsv(self) -> Hsv {
+		let (hue, max, min) = self.hue();
+		let saturation = if max > 0.0 { (max - min) / max } else { 0.0 };
+		Hsv { hue, saturation, value: max, alpha: f64::from(self.alpha) / 255.0 }
+	}
+
+	/// Converts a color from the HSV color model.
+	///
+	/// Out of range components are clamped, the hue wraps around.
+	pub fn from_hsv(hsv: Hsv) -> ColorValue {
+		let value = hsv.value.clamp(0.0, 1.0);
+		let chroma = value * hsv.saturation.clamp(0.0, 1.0);
+		from_chroma(hsv.hue, chroma, value - chroma, hsv.alpha)
+	}
+
+	/// Converts the color to the HSL color model.
+	pub fn to_hsl(self) -> Hsl {
+		let (hue, max, min) = self.hue();
+		let lightness = (max + min) / 2.0;
+		let saturation = if max > min { (max - min) / (1.0 - (2.0 * lightness - 1.0).abs()) } else { 0.0 };
+		Hsl { hue, saturation, lightness, alpha: f64::from(self.alpha) / 255.0 }
+	}
+
+	/// Converts a color from the HSL color model.
+	///
+	/// Out of range components are clamped, the hue wraps around.
+	pub fn from_hsl(hsl: Hsl) -> ColorValue {
+		let lightness = hsl.lightness.clamp(0.0, 1.0);
+		let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * hsl.saturation.clamp(0.0, 1.0);
+		from_chroma(hsl.hue, chroma, lightness - chroma / 2.0, hsl.alpha)
+	}
+
+	/// Looks up a named CSS color, ignoring case.
+	pub fn from_name(name: &str) -> Option<ColorValue> {
+		let name = name.to_ascii_lowercase();
+		if name == "transparent" {
+			return Some(ColorValue::rgba(0, 0, 0, 0));
+		}
+		let index = NAMED_COLORS.binary_search_by_key(&name.as_str(), |&(name, _)| name).ok()?;
+		let [_, red, green, blue] = NAMED_COLORS[index].1.to_be_bytes();
+		Some(ColorValue::rgb(red, green, blue))
+	}
+
+	// Returns the hue in degrees and the largest and smallest components in the range [0, 1].
+	fn hue(self) -> (f64, f64, f64) {
+		let red = f64::from(self.red) / 255.0;
+		let green = f64::from(self.green) / 255.0;
+		let blue = f64::from(self.blue) / 255.0;
+		let max = red.max(green).max(blue);
+		let min = red.min(green).min(blue);
+		let delta = max - min;
+
+		let hue = if delta == 0.0 {
+			0.0
+		}
+		else if max == red {
+			60.0 * ((green - blue) / delta).rem_euclid(6.0)
+		}
+		else if max == green {
+			60.0 * ((blue - red) / delta + 2.0)
+		}
+		else {
+			60.0 * ((red - green) / delta + 4.0)
+		};
+		(hue, max, min)
+	}
+}
+
+fn from_chroma(hue: f64, chroma: f64, offset: f64, alpha: f64) -> ColorValue {
+	let sector = hue.rem_euclid(360.0) / 60.0;
+	let x = chroma * (1.0 - (sector.rem_euclid(2.0) - 1.0).abs());
+	let (red, green, blue) = match sector as u32 {
+		0 => (chroma, x, 0.0),
+		1 => (x, chroma, 0.0),
+		2 => (0.0, chroma, x),
+		3 => (0.0, x, chroma),
+		4 => (x, 0.0, chroma),
+		_ => (chroma, 0.0, x),
+	};
+	ColorValue {
+		red: unit_to_u8(red + offset),
+		green: unit_to_u8(green + offset),
+		blue: unit_to_u8(blue + offset),
+		alpha: unit_to_u8(alpha),
+	}
+}
+
+fn unit_to_u8(value: f64) -> u8 {
+	(value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+impl fmt::Display for ColorValue {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let ColorValue { red, green, blue, alpha } = *self;
+		match (f.alternate(), alpha) {
+			(false, 255) => write!(f, "#{red:02X}{green:02X}{blue:02X}"),
+			(false, _) => write!(f, "#{red:02X}{green:02X}{blue:02X}{alpha:02X}"),
+			(true, 255) => write!(f, "rgb({red}, {green}, {blue})"),
+			// Three decimals are enough to round trip every alpha value.
+			(true, _) => write!(f, "rgba({red}, {green}, {blue}, {})", (f64::from(alpha) / 255.0 * 1000.0).round() / 1000.0),
+		}
+	}
+}
+
+impl FromStr for ColorValue {
+	type Err = ParseColorError;
+
+	fn from_str(s: &str) -> Result<ColorValue, ParseColorError> {
+		let value = s.trim();
+		let color = if let Some(hex) = value.strip_prefix('#') {
+			parse_hex(hex)
+		}
+		else if let Some((name, args)) = value.strip_suffix(')').and_then(|value| value.split_once('(')) {
+			parse_function(name.trim(), args)
+		}
+		else {
+			ColorValue::from_name(value)
+		};
+		color.ok_or(ParseColorError)
+	}
+}
+
+fn parse_hex(hex: &str) -> Option<ColorValue> {
+	if !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+		return None;
+	}
+	let digit = |index: usize| u8::from_str_radix(&hex[index..index + 1], 16).ok();
+	let byte = |index: usize| u8::from_str_radix(&hex[index * 2..index * 2 + 2], 16).ok();
+	match hex.len() {
+		3 => Some(ColorValue::rgb(digit(0)? * 17, digit(1)? * 17, digit(2)? * 17)),
+		4 => Some(ColorValue::rgba(digit(0)? * 17, digit(1)? * 17, digit(2)? * 17, digit(3)? * 17)),
+		6 => Some(ColorValue::rgb(byte(0)?, byte(1)?, byte(2)?)),
+		8 => Some(ColorValue::rgba(byte(0)?, byte(1)?, byte(2)?, byte(3)?)),
+		_ => None,
+	}
+}
+
+// Accepts both the legacy comma separated and the modern space separated syntax.
+fn parse_function(name: &str, args: &str) -> Option<ColorValue> {
+	let args = args.split([',', '/', ' ', '\t']).filter(|arg| !arg.is_empty()).collect::<Vec<_>>();
+	let alpha = match args.get(3) {
+		Some(alpha) => parse_number(alpha, 1.0)?,
+		None => 1.0,
+	};
+	if args.len() < 3 || args.len() > 4 {
+		return None;
+	}
+
+	match name.to_ascii_lowercase().as_str() {
+		"rgb" | "rgba" => Some(ColorValue {
+			red: unit_to_u8(parse_number(args[0], 255.0)?),
+			green: unit_to_u8(parse_number(args[1], 255.0)?),
+			blue: unit_to_u8(parse_number(args[2], 255.0)?),
+			alpha: unit_to_u8(alpha),
+		}),
+		"hsl" | "hsla" => {
+			let hue = args[0].strip_suffix("deg").unwrap_or(args[0]).parse::<f64>().ok()?;
+			let saturation = parse_number(args[1], 100.0)?;
+			let lightness = parse_number(args[2], 100.0)?;
+			Some(ColorValue::from_hsl(Hsl { hue, saturation, lightness, alpha }))
+		}
+		_ => None,
+	}
+}
+
+// Parses a number or percentage, scaled to the range [0, 1].
+fn parse_number(value: &str, scale: f64) -> Option<f64> {
+	let number = match value.strip_suffix('%') {
+		Some(percent) => percent.parse::<f64>().ok()? / 100.0,
+		None => value.parse::<f64>().ok()? / scale,
+	};
+	number.is_finite().then_some(number)
+}
+
+static NAMED_COLORS: [(&str, u32); 148] = [
+	("aliceblue", 0xF0F8FF),
+	("antiquewhite", 0xFAEBD7),
+	("aqua", 0x00FFFF),
+	("aquamarine", 0x7FFFD4),
+	("azure", 0xF0FFFF),
+	("beige", 0xF5F5DC),
+	("bisque", 0xFFE4C4),
+	("black", 0x000000),
+	("blanchedalmond", 0xFFEBCD),
+	("blue", 0x0000FF),
+	("blueviolet", 0x8A2BE2),
+	("brown", 0xA52A2A),
+	("burlywood", 0xDEB887),
+	("cadetblue", 0x5F9EA0),
+	("chartreuse", 0x7FFF00),
+	("chocolate", 0xD2691E),
+	("coral", 0xFF7F50),
+	("cornflowerblue", 0x6495ED),
+	("cornsilk", 0xFFF8DC),
+	("crimson", 0xDC143C),
+	("cyan", 0x00FFFF),
+	("darkblue", 0x00008B),
+	("darkcyan", 0x008B8B),
+	("darkgoldenrod", 0xB8860B),
+	("darkgray", 0xA9A9A9),
+	("darkgreen", 0x006400),
+	("darkgrey", 0xA9A9A9),
+	("darkkhaki", 0xBDB76B),
+	("darkmagenta", 0x8B008B),
+	("darkolivegreen", 0x556B2F),
+	("darkorange", 0xFF8C00),
+	("darkorchid", 0x9932CC),
+	("darkred", 0x8B0000),
+	("darksalmon", 0xE9967A),
+	("darkseagreen", 0x8FBC8F),
+	("darkslateblue", 0x483D8B),
+	("darkslategray", 0x2F4F4F),
+	("darkslategrey", 0x2F4F4F),
+	("darkturquoise", 0x00CED1),
+	("darkviolet", 0x9400D3),
+	("deeppink", 0xFF1493),
+	("deepskyblue", 0x00BFFF),
+	("dimgray", 0x696969),
+	("dimgrey", 0x696969),
+	("dodgerblue", 0x1E90FF),
+	("firebrick", 0xB22222),
+	("floralwhite", 0xFFFAF0),
+	("forestgreen", 0x228B22),
+	("fuchsia", 0xFF00FF),
+	("gainsboro", 0xDCDCDC),
+	("ghostwhite", 0xF8F8FF),
+	("gold", 0xFFD700),
+	("goldenrod", 0xDAA520),
+	("gray", 0x808080),
+	("green", 0x008000),
+	("greenyellow", 0xADFF2F),
+	("grey", 0x808080),
+	("honeydew", 0xF0FFF0),
+	("hotpink", 0xFF69B4),
+	("indianred", 0xCD5C5C),
+	("indigo", 0x4B0082),
+	("ivory", 0xFFFFF0),
+	("khaki", 0xF0E68C),
+	("lavender", 0xE6E6FA),
+	("lavenderblush", 0xFFF0F5),
+	("lawngreen", 0x7CFC00),
+	("lemonchiffon", 0xFFFACD),
+	("lightblue", 0xADD8E6),
+	("lightcoral", 0xF08080),
+	("lightcyan", 0xE0FFFF),
+	("lightgoldenrodyellow", 0xFAFAD2),
+	("lightgray", 0xD3D3D3),
+	("lightgreen", 0x90EE90),
+	("lightgrey", 0xD3D3D3),
+	("lightpink", 0xFFB6C1),
+	("lightsalmon", 0xFFA07A),
+	("lightseagreen", 0x20B2AA),
+	("lightskyblue", 0x87CEFA),
+	("lightslategray", 0x778899),
+	("lightslategrey", 0x778899),
+	("lightsteelblue", 0xB0C4DE),
+	("lightyellow", 0xFFFFE0),
+	("lime", 0x00FF00),
+	("limegreen", 0x32CD32),
+	("linen", 0xFAF0E6),
+	("magenta", 0xFF00FF),
+	("maroon", 0x800000),
+	("mediumaquamarine", 0x66CDAA),
+	("mediumblue", 0x0000CD),
+	("mediumorchid", 0xBA55D3),
+	("mediumpurple", 0x9370DB),
+	("mediumseagreen", 0x3CB371),
+	("mediumslateblue", 0x7B68EE),
+	("mediumspringgreen", 0x00FA9A),
+	("mediumturquoise", 0x48D1CC),
+	("mediumvioletred", 0xC71585),
+	("midnightblue", 0x191970),
+	("mintcream", 0xF5FFFA),
+	("mistyrose", 0xFFE4E1),
+	("moccasin", 0xFFE4B5),
+	("navajowhite", 0xFFDEAD),
+	("navy", 0x000080),
+	("oldlace", 0xFDF5E6),
+	("olive", 0x808000),
+	("olivedrab", 0x6B8E23),
+	("orange", 0xFFA500),
+	("orangered", 0xFF4500),
+	("orchid", 0xDA70D6),
+	("palegoldenrod", 0xEEE8AA),
+	("palegreen", 0x98FB98),
+	("paleturquoise", 0xAFEEEE),
+	("palevioletred", 0xDB7093),
+	("papayawhip", 0xFFEFD5),
+	("peachpuff", 0xFFDAB9),
+	("peru", 0xCD853F),
+	("pink", 0xFFC0CB),
+	("plum", 0xDDA0DD),
+	("powderblue", 0xB0E0E6),
+	("purple", 0x800080),
+	("rebeccapurple", 0x663399),
+	("red", 0xFF0000),
+	("rosybrown", 0xBC8F8F),
+	("royalblue", 0x4169E1),
+	("saddlebrown", 0x8B4513),
+	("salmon", 0xFA8072),
+	("sandybrown", 0xF4A460),
+	("seagreen", 0x2E8B57),
+	("seashell", 0xFFF5EE),
+	("sienna", 0xA0522D),
+	("silver", 0xC0C0C0),
+	("skyblue", 0x87CEEB),
+	("slateblue", 0x6A5ACD),
+	("slategray", 0x708090),
+	("slategrey", 0x708090),
+	("snow", 0xFFFAFA),
+	("springgreen", 0x00FF7F),
+	("steelblue", 0x4682B4),
+	("tan", 0xD2B48C),
+	("teal", 0x008080),
+	("thistle", 0xD8BFD8),
+	("tomato", 0xFF6347),
+	("turquoise", 0x40E0D0),
+	("violet", 0xEE82EE),
+	("wheat", 0xF5DEB3),
+	("white", 0xFFFFFF),
+	("whitesmoke", 0xF5F5F5),
+	("yellow", 0xFFFF00),
+	("yellowgreen", 0x9ACD32),
+];
@@ -33,11 +33,16 @@ Untested on macOS. No test report yet.
 
 */
 
+use std::borrow::Cow;
+use std::{fmt, fs};
 use std::path::{Path, PathBuf};
 use raw_window_handle::HasWindowHandle;
 
 mod utils;
 
+pub mod color;
+pub use color::ColorValue;
+
 /// Icon types for message dialogs.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum MessageIcon {
@@ -126,7 +131,20 @@ pub struct FileFilter<'a> {
 	/// The description of the file filter, e.g. `"Text Files"`.
 	pub name: &'a str,
 	/// The file patterns of the file filter, e.g. `&["*.txt"]` or `&["*.jpg", "*.jpeg"]`.
+	///
+	/// Patterns support the `*`, `?` and `[...]` wildcards and brace expansion, e.g. `&["*.{jpg,jpeg}"]`.
+	/// Patterns match case-insensitively on all backends and may contain spaces.
 	pub patterns: &'a [&'a str],
+	/// The MIME types of the file filter, e.g. `&["application/pdf"]` or `&["image/*"]`.
+	///
+	/// Unlike patterns, MIME types match files regardless of the case of their extension.
+	///
+	/// ### Platform-specific behavior
+	///
+	/// - Linux: Matched natively by the XDG portal and GTK. Translated to glob patterns using the shared-mime-info database for `zenity` and `kdialog`.
+	/// - Windows: Translated to the file extension registered for the MIME type. Wildcards are not supported.
+	/// - macOS: Not supported, filters with MIME types allow all files.
+	pub mime_types: &'a [&'a str],
 }
 
 #[allow(dead_code)]
@@ -134,13 +152,71 @@ impl FileFilter<'_> {
 	const ALL_FILES: FileFilter<'static> = FileFilter {
 		name: "All Files",
 		patterns: &["*"],
+		mime_types: &[],
+	};
+	const ICON_FILES: FileFilter<'static> = FileFilter {
+		name: "Icons",
+		patterns: &["*.png", "*.svg", "*.xpm", "*.ico", "*.icns"],
+		mime_types: &[],
 	};
 }
 
+/// Extra choice shown in a file dialog, either a combo box or a checkbox.
+///
+/// ```no_run
+/// let choices = [
+/// 	rustydialogs::FileChoice {
+/// 		id: "encoding",
+/// 		label: "Encoding",
+/// 		options: &[("utf8", "UTF-8"), ("latin1", "Latin-1")],
+/// 		default: "utf8",
+/// 	},
+/// 	rustydialogs::FileChoice {
+/// 		id: "metadata",
+/// 		label: "Include metadata",
+/// 		options: &[],
+/// 		default: "true",
+/// 	},
+/// ];
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct FileChoice<'a> {
+	/// The identifier of the choice in [`FileSelection::choices`].
+	pub id: &'a str,
+	/// The label shown next to the choice.
+	pub label: &'a str,
+	/// The options of a combo box as `(id, label)` pairs, or an empty list for a checkbox.
+	pub options: &'a [(&'a str, &'a str)],
+	/// The id of the initially selected option, or `"true"` or `"false"` for a checkbox.
+	pub default: &'a str,
+}
+
+/// The filter and choices selected in a file dialog.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FileSelection {
+	/// Index into [`FileDialog::filters`] of the selected filter.
+	///
+	/// `None` if the "All Files" filter is selected or the selected filter is not known.
+	pub filter: Option<usize>,
+	/// The selected option id of each of the [`FileDialog::choices`] as `(id, option)` pairs, in the same order.
+	///
+	/// Checkboxes are `"true"` or `"false"`. Backends without choices return the default values.
+	pub choices: Vec<(String, String)>,
+}
+
+impl FileSelection {
+	/// Returns the selected option id of the choice.
+	pub fn choice(&self, id: &str) -> Option<&str> {
+		self.choices.iter().find(|(choice, _)| choice == id).map(|(_, option)| option.as_str())
+	}
+}
+
 /// File dialog.
 ///
 /// The file dialog allows the user to select a file or multiple files, specify a file name for saving, or select folders.
 ///
+/// Fields which are not needed can be left at their [default](#impl-Default-for-FileDialog%3C'_%3E) values with `..Default::default()`.
+///
 /// ```no_run
 /// use std::env;
 ///
@@ -151,9 +227,10 @@ impl FileFilter<'_> {
 /// 		rustydialogs::FileFilter {
 /// 			name: "Text Files",
 /// 			patterns: &["*.txt", "*.md"],
+/// 			mime_types: &[],
 /// 		},
 /// 	]),
-/// 	owner: None,
+/// 	..Default::default()
 /// }.pick_file();
 ///
 /// if let Some(path) = file {
@@ -174,29 +251,177 @@ pub struct FileDialog<'a> {
 	pub path: Option<&'a Path>,
 	/// An optional list of file filters to show in the file dialog.
 	///
-	/// An additional "All Files" filter is automatically added to the end of the list.
+	/// An additional "All Files" filter is added to the end of the list if `all_files` is `true`.
 	pub filters: Option<&'a [FileFilter<'a>]>,
+	/// Index into `filters` of the filter which is selected when the dialog opens.
+	///
+	/// Out of range indices select the first filter.
+	pub default_filter: usize,
+	/// Add an "All Files" filter to the end of `filters`.
+	pub all_files: bool,
+	/// The extension without the leading dot, e.g. `"txt"`, appended to the saved file name when the user enters a name without extension.
+	///
+	/// The extension of the selected filter is used instead when its first pattern has the form `*.ext`.
+	/// If a file with the final name already exists, the user is asked to confirm overwriting it.
+	pub default_extension: Option<&'a str>,
+	/// Show hidden files, e.g. files starting with a dot on Linux and macOS.
+	///
+	/// Not supported by the XDG portal, `zenity`, `kdialog` and GTK 4, press Ctrl+H in the dialog to show hidden files instead.
+	pub show_hidden: bool,
+	/// Allow creating new folders from save and folder dialogs.
+	///
+	/// Not supported by the XDG portal, `zenity`, `kdialog` and the Windows file dialogs, which always allow creating folders.
+	pub create_folders: bool,
+	/// Only allow selecting local files.
+	///
+	/// Only GTK 3 supports browsing remote locations, they are returned through their local mount path.
+	/// The other backends always select local files.
+	pub local_only: bool,
+	/// Ask for confirmation before replacing an existing file in save dialogs.
+	///
+	/// The XDG portal, GTK 4 and macOS always ask for confirmation.
+	pub confirm_overwrite: bool,
+	/// The label of the accept button, e.g. `"Import"`, instead of "Open", "Save" or "Select".
+	///
+	/// Not supported by `kdialog`, the AppleScript dialogs on macOS and on Windows, except when choosing multiple folders.
+	pub accept_label: Option<&'a str>,
+	/// The label of the cancel button.
+	///
+	/// Only supported by GTK and `zenity`.
+	pub cancel_label: Option<&'a str>,
+	/// Extra choices shown in open and save file dialogs, returned by the `*_with_selection` methods.
+	///
+	/// Only supported by the XDG portal and GTK, the other backends return the default values.
+	pub choices: &'a [FileChoice<'a>],
+	/// Remember the last used directory under this key and start there the next time a file dialog with the same key and without `path` is shown.
+	///
+	/// Use a different key for each purpose, e.g. `"import"` and `"export"`.
+	/// The URI methods only use the remembered directory, they do not remember their result.
+	/// Directories are stored in `$XDG_STATE_HOME/rustydialogs` on Linux, `%LOCALAPPDATA%\rustydialogs` on Windows and `~/Library/Application Support/rustydialogs` on macOS.
+	pub key: Option<&'a str>,
 	/// The owner window of the dialog.
 	pub owner: Option<&'a dyn HasWindowHandle>,
 }
 
+impl Default for FileDialog<'_> {
+	/// An untitled dialog without path and filters.
+	///
+	/// `all_files`, `create_folders`, `local_only` and `confirm_overwrite` are `true`, the other options are off.
+	fn default() -> Self {
+		FileDialog {
+			title: "",
+			path: None,
+			filters: None,
+			default_filter: 0,
+			all_files: true,
+			default_extension: None,
+			show_hidden: false,
+			create_folders: true,
+			local_only: true,
+			confirm_overwrite: true,
+			accept_label: None,
+			cancel_label: None,
+			choices: &[],
+			key: None,
+			owner: None,
+		}
+	}
+}
+
 impl<'a> FileDialog<'a> {
 	/// Show open file dialog, allowing the user to select a single file.
 	#[inline]
 	pub fn pick_file(&self) -> Option<PathBuf> {
-		pick_file(self)
+		self.pick_file_with_selection().map(|(path, _)| path)
 	}
 
 	/// Show open file dialog, allowing the user to select multiple files.
 	#[inline]
 	pub fn pick_files(&self) -> Option<Vec<PathBuf>> {
-		pick_files(self)
+		self.pick_files_with_selection().map(|(paths, _)| paths)
 	}
 
 	/// Show save file dialog.
 	#[inline]
 	pub fn save_file(&self) -> Option<PathBuf> {
-		save_file(self)
+		self.save_file_with_selection().map(|(path, _)| path)
+	}
+
+	/// Show open file dialog like [`pick_file`](Self::pick_file), also returning the selected filter and choices.
+	///
+	/// The filter is `None` if the "All Files" filter is selected or the selected filter is not known.
+	///
+	/// ### Platform-specific behavior
+	///
+	/// - Linux: `zenity` and `kdialog` do not report the selected filter, it is guessed from the file name instead.
+	///   They also cannot preselect a filter, the default filter is moved to the front of the list instead.
+	/// - macOS: The selected filter is not known.
+	#[inline]
+	pub fn pick_file_with_selection(&self) -> Option<(PathBuf, FileSelection)> {
+		self.remember_directory(pick_file, |(path, _)| path.parent())
+	}
+
+	/// Show open file dialog like [`pick_files`](Self::pick_files), also returning the selected filter and choices.
+	///
+	/// See [`pick_file_with_selection`](Self::pick_file_with_selection) for the platform-specific behavior.
+	#[inline]
+	pub fn pick_files_with_selection(&self) -> Option<(Vec<PathBuf>, FileSelection)> {
+		self.remember_directory(pick_files, |(paths, _)| paths.first()?.parent())
+	}
+
+	/// Show save file dialog like [`save_file`](Self::save_file), also returning the selected filter and choices.
+	///
+	/// See [`pick_file_with_selection`](Self::pick_file_with_selection) for the platform-specific behavior.
+	#[inline]
+	pub fn save_file_with_selection(&self) -> Option<(PathBuf, FileSelection)> {
+		self.remember_directory(utils::save_file, |(path, _)| path.parent())
+	}
+
+	/// Show a dialog to save multiple files at once, returning the final path of each file.
+	///
+	/// The `names` are the proposed file names, e.g. `&["image1.png", "image2.png"]`, the paths are returned in the same order.
+	/// Returns `None` without showing a dialog if a name is not a plain file name, e.g. `"../image.png"` or an absolute path.
+	///
+	/// ### Platform-specific behavior
+	///
+	/// - Linux: The XDG portal has a native dialog, which may rename files to avoid conflicts.
+	///   If files would still be replaced and `confirm_overwrite` is set, it asks afterwards and returns `None` when the user declines.
+	///   The other backends choose a folder instead and ask before replacing existing files if `confirm_overwrite` is set.
+	/// - Windows & macOS: Chooses a folder and asks before replacing existing files if `confirm_overwrite` is set.
+	#[inline]
+	pub fn save_files(&self, names: &[&str]) -> Option<Vec<PathBuf>> {
+		self.remember_directory(|p| save_files(p, names), |paths| paths.first()?.parent())
+	}
+
+	/// Show open file dialog like [`pick_file`](Self::pick_file), returning the URI of the selected file.
+	///
+	/// Unlike [`pick_file`](Self::pick_file) this also returns files in remote locations without a local path, e.g. `smb://` or `sftp://` URIs.
+	/// Local files are returned as `file://` URIs.
+	///
+	/// ### Platform-specific behavior
+	///
+	/// - Linux: Only the XDG portal, GTK and `kdialog` return remote locations. GTK 3 also requires `local_only` to be `false`.
+	/// - Windows & macOS: Only local files are returned.
+	#[inline]
+	pub fn pick_uri(&self) -> Option<String> {
+		self.remember_directory(pick_uri, |_| None)
+	}
+
+	/// Show open file dialog like [`pick_files`](Self::pick_files), returning the URIs of the selected files.
+	///
+	/// See [`pick_uri`](Self::pick_uri) for the platform-specific behavior.
+	#[inline]
+	pub fn pick_uris(&self) -> Option<Vec<String>> {
+		self.remember_directory(pick_uris, |_| None)
+	}
+
+	/// Show save file dialog like [`save_file`](Self::save_file), returning the URI of the selected file.
+	///
+	/// See [`pick_uri`](Self::pick_uri) for the platform-specific behavior.
+	/// The XDG portal, GTK and `kdialog` do not append the `default_extension`, and `kdialog` does not ask before replacing a file.
+	#[inline]
+	pub fn save_uri(&self) -> Option<String> {
+		self.remember_directory(save_uri, |_| None)
 	}
 
 	/// Show folder picker dialog, allowing the user to select a single folder.
@@ -204,7 +429,7 @@ impl<'a> FileDialog<'a> {
 	/// The `filters` field is ignored for folder selection.
 	#[inline]
 	pub fn choose_folder(&self) -> Option<PathBuf> {
-		choose_folder(self)
+		self.remember_directory(choose_folder, |path| Some(path))
 	}
 
 	/// Show folder picker dialog, allowing the user to select multiple folders.
@@ -218,7 +443,18 @@ impl<'a> FileDialog<'a> {
 	/// - Linux: `kdialog`.
 	#[inline]
 	pub fn choose_folders(&self) -> Option<Vec<PathBuf>> {
-		choose_folders(self)
+		self.remember_directory(choose_folders, |paths| paths.first().map(PathBuf::as_path))
+	}
+
+	// Starts in the directory remembered under `key` when there is no `path` and remembers the directory of the result.
+	fn remember_directory<T>(&self, show: impl FnOnce(&FileDialog<'_>) -> Option<T>, directory: impl FnOnce(&T) -> Option<&Path>) -> Option<T> {
+		let last_directory = self.path.is_none().then(|| utils::last_directory(self.key)).flatten();
+		let dialog = FileDialog { path: self.path.or(last_directory.as_deref()), ..*self };
+		let result = show(&dialog)?;
+		if let (Some(key), Some(directory)) = (self.key, directory(&result)) {
+			utils::save_last_directory(key, directory);
+		}
+		Some(result)
 	}
 }
 
@@ -274,20 +510,268 @@ impl<'a> TextInput<'a> {
 	}
 }
 
-/// Color value.
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-pub struct ColorValue {
-	/// The red component of the color, in the range [0, 255].
-	pub red: u8,
-	/// The green component of the color, in the range [0, 255].
-	pub green: u8,
-	/// The blue component of the color, in the range [0, 255].
-	pub blue: u8,
+/// Credentials entered in a login dialog.
+#[derive(Clone, Eq, PartialEq)]
+pub struct Credentials {
+	/// The user name.
+	pub username: String,
+	/// The password.
+	pub password: String,
+}
+
+impl fmt::Debug for Credentials {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Credentials")
+			.field("username", &self.username)
+			.field("password", &"***")
+			.finish()
+	}
+}
+
+/// Login dialog.
+///
+/// The login dialog asks the user for a user name and a password at the same time.
+///
+/// The password is never passed on the command line of any helper process.
+///
+/// ```no_run
+/// let credentials = rustydialogs::LoginDialog {
+/// 	title: "Connect to Server",
+/// 	message: "Enter your credentials:",
+/// 	username: "",
+/// 	owner: None,
+/// }.show();
+///
+/// if let Some(credentials) = credentials {
+/// 	println!("Logging in as {}", credentials.username);
+/// }
+/// ```
+#[derive(Copy, Clone)]
+pub struct LoginDialog<'a> {
+	/// The title of the dialog.
+	pub title: &'a str,
+	/// The message to display to the user.
+	pub message: &'a str,
+	/// The initial user name.
+	pub username: &'a str,
+	/// The owner window of the dialog.
+	pub owner: Option<&'a dyn HasWindowHandle>,
+}
+
+impl<'a> LoginDialog<'a> {
+	/// Show the dialog.
+	///
+	/// Returns `Some(Credentials)` if the user confirmed the dialog, or `None` if the user cancelled the dialog.
+	///
+	/// ### Platform-specific behavior
+	///
+	/// Backends without a native login dialog ask for the user name and the password one after the other:
+	///
+	/// - Windows.
+	/// - Linux: `kdialog`.
+	/// - macOS.
+	///
+	/// With `zenity` the initial user name is ignored.
+	#[inline]
+	pub fn show(&self) -> Option<Credentials> {
+		login_dialog(self)
+	}
+}
+
+/// Content of a text viewer.
+#[derive(Copy, Clone, Debug)]
+pub enum TextContent<'a> {
+	/// Show the given text.
+	Text(&'a str),
+	/// Show the contents of the given text file.
+	File(&'a Path),
+}
+
+#[allow(dead_code)]
+impl<'a> TextContent<'a> {
+	fn load(self) -> Option<Cow<'a, str>> {
+		match self {
+			TextContent::Text(text) => Some(Cow::Borrowed(text)),
+			TextContent::File(path) => {
+				let bytes = fs::read(path).ok()?;
+				Some(Cow::Owned(String::from_utf8_lossy(&bytes).into_owned()))
+			}
+		}
+	}
+}
+
+/// Text viewer dialog.
+///
+/// Shows a read-only, scrollable text such as a license agreement or a changelog.
+///
+/// ```no_run
+/// let accepted = rustydialogs::TextViewer {
+/// 	title: "License Agreement",
+/// 	content: rustydialogs::TextContent::File(std::path::Path::new("LICENSE")),
+/// 	checkbox: Some("I agree to the terms of the license"),
+/// 	owner: None,
+/// }.show();
+///
+/// if !accepted {
+/// 	return;
+/// }
+/// ```
+#[derive(Copy, Clone)]
+pub struct TextViewer<'a> {
+	/// The title of the dialog.
+	pub title: &'a str,
+	/// The text to show in the dialog.
+	pub content: TextContent<'a>,
+	/// An optional checkbox label, e.g. `"I agree"`.
+	///
+	/// When set, the dialog can only be accepted once the user checked the checkbox.
+	pub checkbox: Option<&'a str>,
+	/// The owner window of the dialog.
+	pub owner: Option<&'a dyn HasWindowHandle>,
+}
+
+impl<'a> TextViewer<'a> {
+	/// Show the dialog.
+	///
+	/// Returns `true` if the user accepted the dialog, or `false` if the user cancelled the dialog or the file could not be read.
+	///
+	/// ### Platform-specific behavior
+	///
+	/// Backends without a native checkbox ask a follow-up question with the checkbox label instead:
+	///
+	/// - Windows.
+	/// - Linux: `kdialog`.
+	/// - macOS.
+	///
+	/// On Windows and macOS the text is shown in a message box, which cannot scroll.
+	/// Long texts are cut off, for [`TextContent::File`] the message then names the file to read the full text.
+	///
+	/// Not available with the `xdg-portal` backend, always returns `false`.
+	#[inline]
+	pub fn show(&self) -> bool {
+		text_viewer(self)
+	}
+}
+
+/// About dialog.
+///
+/// Shows the program name, version, description, authors, website and license of the application.
+///
+/// ```no_run
+/// rustydialogs::AboutDialog {
+/// 	name: "Rusty Editor",
+/// 	version: "1.0.0",
+/// 	icon: "accessories-text-editor",
+/// 	comments: "A simple text editor.",
+/// 	license: "Permission is hereby granted, free of charge, ...",
+/// 	authors: &["Jane Doe <jane@example.com>"],
+/// 	website: "https://example.com",
+/// 	owner: None,
+/// }.show();
+/// ```
+#[derive(Copy, Clone)]
+pub struct AboutDialog<'a> {
+	/// The program name.
+	pub name: &'a str,
+	/// The program version, or empty if not shown.
+	pub version: &'a str,
+	/// The icon name from the desktop icon theme, or empty for the default icon.
+	pub icon: &'a str,
+	/// A short description of the program, or empty if not shown.
+	pub comments: &'a str,
+	/// The license text, or empty if not shown.
+	pub license: &'a str,
+	/// The authors of the program.
+	pub authors: &'a [&'a str],
+	/// The website of the program, or empty if not shown.
+	pub website: &'a str,
+	/// The owner window of the dialog.
+	pub owner: Option<&'a dyn HasWindowHandle>,
+}
+
+impl<'a> AboutDialog<'a> {
+	/// Show the dialog.
+	///
+	/// Blocks until the user closes the dialog.
+	///
+	/// ### Platform-specific behavior
+	///
+	/// - Windows: The information is shown in a message box, the license text and icon are not shown.
+	/// - Linux: `zenity` and `kdialog` show the license text in a separate dialog from a License button.
+	/// - macOS: The information is shown in a message box, the license text and icon are not shown.
+	/// - Not available with the `xdg-portal` backend, does nothing.
+	#[inline]
+	pub fn show(&self) {
+		about_dialog(self)
+	}
+}
+
+/// Log window.
+///
+/// A non-blocking window showing log lines as they are produced, e.g. while running an installer.
+/// The Close button only becomes active once [`finish`](LogWindow::finish) is called.
+///
+/// ```no_run
+/// let mut log = rustydialogs::LogWindow::new("Installing");
+/// for line in ["Downloading...", "Extracting...", "Configuring..."] {
+/// 	log.append_line(line);
+/// }
+/// log.set_title("Installation complete");
+/// log.finish();
+/// log.wait_closed();
+/// ```
+///
+/// Dropping the handle closes the window.
+///
+/// ### Platform-specific behavior
+///
+/// - Linux: GTK3 and GTK4 run the window on the calling thread and only handle its events (redrawing, resizing, scrolling) while one of these methods runs.
+///   Call [`append_line`](LogWindow::append_line) or [`set_title`](LogWindow::set_title) regularly, the window does not respond while the caller is busy in between.
+/// - Linux: `zenity` cannot change the title or disable its buttons, the user may close the window early.
+/// - Linux: `kdialog` has no live output, no window is shown at all until [`wait_closed`](LogWindow::wait_closed) is called, which then shows the collected log.
+/// - Not available on Windows, macOS and with the `xdg-portal` backend, does nothing.
+pub struct LogWindow {
+	inner: LogWindowImpl,
+}
+
+impl LogWindow {
+	/// Open a new log window with the given title.
+	#[inline]
+	pub fn new(title: &str) -> LogWindow {
+		LogWindow { inner: LogWindowImpl::new(title) }
+	}
+
+	/// Append a line of text to the log and scroll to the end.
+	#[inline]
+	pub fn append_line(&mut self, line: &str) {
+		self.inner.append_line(line)
+	}
+
+	/// Change the title of the window.
+	#[inline]
+	pub fn set_title(&mut self, title: &str) {
+		self.inner.set_title(title)
+	}
+
+	/// Mark the job as finished and activate the Close button.
+	#[inline]
+	pub fn finish(&mut self) {
+		self.inner.finish()
+	}
+
+	/// Block until the user closes the window.
+	///
+	/// Implies [`finish`](LogWindow::finish).
+	#[inline]
+	pub fn wait_closed(mut self) {
+		self.inner.finish();
+		self.inner.wait_closed()
+	}
 }
 
 /// Color picker dialog.
 ///
-/// The color picker dialog allows the user to select a color, which is returned as an RGB value.
+/// The color picker dialog allows the user to select a color, which is returned as an RGBA value.
 /// The dialog may also show a palette of predefined colors for the user to choose from.
 ///
 /// ```no_run
@@ -297,7 +781,11 @@ pub struct ColorValue {
 /// 		red: 64,
 /// 		green: 128,
 /// 		blue: 255,
+/// 		alpha: 255,
 /// 	},
+/// 	alpha: false,
+/// 	palette: &[],
+/// 	key: Some("background"),
 /// 	owner: None,
 /// }.show();
 ///
@@ -311,6 +799,16 @@ pub struct ColorPicker<'a> {
 	pub title: &'a str,
 	/// The initial color value to show in the color picker dialog.
 	pub value: ColorValue,
+	/// Let the user pick the alpha component.
+	///
+	/// If `false`, the returned color is always fully opaque.
+	pub alpha: bool,
+	/// Colors to offer in the dialog, e.g. the brand colors of the application.
+	pub palette: &'a [ColorValue],
+	/// Remember picked colors under this key and offer them again the next time a color picker with the same key is shown.
+	///
+	/// Recent colors are stored in `$XDG_STATE_HOME/rustydialogs` on Linux, `%LOCALAPPDATA%\rustydialogs` on Windows and `~/Library/Application Support/rustydialogs` on macOS.
+	pub key: Option<&'a str>,
 	/// The owner window of the dialog.
 	pub owner: Option<&'a dyn HasWindowHandle>,
 }
@@ -319,9 +817,360 @@ impl<'a> ColorPicker<'a> {
 	/// Show the dialog.
 	///
 	/// Returns `Some(ColorValue)` if the user selected a color and confirmed the dialog, or `None` if the user cancelled the dialog.
+	///
+	/// ### Platform-specific behavior
+	///
+	/// - Windows: The alpha component cannot be picked, the alpha of the initial value is returned.
+	/// - Linux: `kdialog` cannot pick the alpha component, the alpha of the initial value is returned.
+	/// - macOS: The alpha component can only be picked with the `appkit` feature, otherwise the alpha of the initial value is returned.
+	/// - Windows: The palette and recent colors are shown as custom colors, up to 16 in total.
+	/// - Linux: `zenity` can only show its default palette, `kdialog` shows no palette. Picked colors are still remembered.
+	/// - macOS: The palette and recent colors are not shown, picked colors are still remembered.
+	/// - Linux: The `xdg-portal` backend has no color dialog, the color is picked from the screen like [`pick_from_screen`](Self::pick_from_screen).
 	#[inline]
 	pub fn show(&self) -> Option<ColorValue> {
-		color_picker(self)
+		let color = color_picker(self);
+		if let (Some(key), Some(color)) = (self.key, color) {
+			utils::save_recent_color(key, color);
+		}
+		color
+	}
+
+	/// Picks a color from anywhere on the screen with an eyedropper.
+	///
+	/// Returns `None` if the user cancels or picking from the screen is not available.
+	///
+	/// The title, palette and owner are not used.
+	/// The alpha component cannot be picked, the alpha of the initial value is returned if `alpha` is `true`.
+	///
+	/// ### Platform-specific behavior
+	///
+	/// - Linux: Requires the `xdg-portal` feature and a running desktop portal, works with every backend including under Wayland.
+	/// - Windows and macOS: Not supported, always returns `None`.
+	#[inline]
+	pub fn pick_from_screen(&self) -> Option<ColorValue> {
+		let color = pick_color_from_screen(self);
+		if let (Some(key), Some(color)) = (self.key, color) {
+			utils::save_recent_color(key, color);
+		}
+		color
+	}
+}
+
+/// Font style.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum FontStyle {
+	/// Upright font style.
+	Normal,
+	/// Slanted version of the upright font style.
+	Oblique,
+	/// Italic font style.
+	Italic,
+}
+
+/// Font value.
+///
+/// Formats as a Pango font description string (e.g. `"Sans Bold Italic 12"`) which can be passed back into [`FontPicker::value`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct FontValue {
+	/// The font family name.
+	pub family: String,
+	/// The font style.
+	pub style: FontStyle,
+	/// The font weight, in the range [100, 1000] where 400 is regular and 700 is bold.
+	pub weight: u16,
+	/// The font size in points, or `0.0` if not specified.
+	pub size: f32,
+}
+
+impl fmt::Display for FontValue {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(&self.family)?;
+		if let Some(name) = utils::font_weight_name(self.weight) {
+			write!(f, " {name}")?;
+		}
+		match self.style {
+			FontStyle::Normal => {}
+			FontStyle::Oblique => f.write_str(" Oblique")?,
+			FontStyle::Italic => f.write_str(" Italic")?,
+		}
+		if self.size > 0.0 {
+			write!(f, " {}", self.size)?;
+		}
+		Ok(())
+	}
+}
+
+/// Font picker dialog.
+///
+/// The font picker dialog allows the user to select a font family, style, weight and size.
+///
+/// ```no_run
+/// let font = rustydialogs::FontPicker {
+/// 	title: "Pick a Font",
+/// 	value: "Monospace 11",
+/// 	owner: None,
+/// }.show();
+///
+/// if let Some(font) = font {
+/// 	println!("{} (weight {}, {:?}) at {}pt", font.family, font.weight, font.style, font.size);
+/// }
+/// ```
+#[derive(Copy, Clone)]
+pub struct FontPicker<'a> {
+	/// The title of the dialog.
+	pub title: &'a str,
+	/// The initial font as a Pango font description string, e.g. `"Sans Bold 12"`.
+	pub value: &'a str,
+	/// The owner window of the dialog.
+	pub owner: Option<&'a dyn HasWindowHandle>,
+}
+
+impl<'a> FontPicker<'a> {
+	/// Show the dialog.
+	///
+	/// Returns `Some(FontValue)` if the user selected a font and confirmed the dialog, or `None` if the user cancelled the dialog.
+	///
+	/// ### Platform-specific behavior
+	///
+	/// - Windows: The size is only approximate as the initial font height assumes a 96 DPI screen.
+	/// - Linux: `zenity` has no font chooser and shows a form instead, empty fields keep the initial value.
+	/// - Linux: `kdialog` has no font chooser and asks for a font description string instead.
+	/// - macOS: No modal font chooser is available, asks for a font description string instead.
+	/// - Not available with the `xdg-portal` backend, always returns `None`.
+	#[inline]
+	pub fn show(&self) -> Option<FontValue> {
+		font_picker(self)
+	}
+}
+
+/// Page orientation.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum PageOrientation {
+	/// Portrait orientation.
+	Portrait,
+	/// Landscape orientation.
+	Landscape,
+	/// Upside down portrait orientation.
+	ReversePortrait,
+	/// Upside down landscape orientation.
+	ReverseLandscape,
+}
+
+/// Print settings and page setup selected in the print dialog.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PrintSettings {
+	/// The name of the selected printer.
+	pub printer: String,
+	/// The number of copies to print.
+	pub copies: u32,
+	/// The pages to print as inclusive ranges of page numbers starting at 1, or empty to print all pages.
+	pub page_ranges: Vec<(u32, u32)>,
+	/// The paper size name in PWG format (e.g. `"iso_a4"` or `"na_letter"`), or empty if unknown.
+	pub paper: String,
+	/// The paper width in millimeters, in portrait orientation.
+	pub paper_width: f64,
+	/// The paper height in millimeters, in portrait orientation.
+	pub paper_height: f64,
+	/// The page orientation.
+	pub orientation: PageOrientation,
+	// Token returned by the print portal to print without showing another dialog.
+	#[allow(dead_code)]
+	token: Option<u32>,
+}
+
+impl PrintSettings {
+	/// Print a file with these settings.
+	///
+	/// The file should be in a format the printing system understands, typically PDF.
+	/// The title identifies the print job.
+	///
+	/// Returns `true` if the print job was submitted.
+	///
+	/// ### Platform-specific behavior
+	///
+	/// - Linux: Settings from the `xdg-portal` backend are submitted through the print portal, otherwise the file is printed with the CUPS `lp` command.
+	/// - Not available on Windows and macOS, always returns `false`.
+	#[inline]
+	pub fn print_file(&self, title: &str, path: &Path) -> bool {
+		print_file(self, title, path)
+	}
+}
+
+/// Print dialog.
+///
+/// Asks the user for a printer and page setup before the application renders its document.
+///
+/// ```no_run
+/// let settings = rustydialogs::PrintDialog {
+/// 	title: "Print Document",
+/// 	owner: None,
+/// }.show();
+///
+/// if let Some(settings) = settings {
+/// 	println!("Printing {} copies on {}", settings.copies, settings.printer);
+/// 	settings.print_file("Document", std::path::Path::new("document.pdf"));
+/// }
+/// ```
+#[derive(Copy, Clone)]
+pub struct PrintDialog<'a> {
+	/// The title of the dialog.
+	pub title: &'a str,
+	/// The owner window of the dialog.
+	pub owner: Option<&'a dyn HasWindowHandle>,
+}
+
+impl<'a> PrintDialog<'a> {
+	/// Show the dialog.
+	///
+	/// Returns `Some(PrintSettings)` if the user confirmed the dialog, or `None` if the user cancelled the dialog.
+	///
+	/// ### Platform-specific behavior
+	///
+	/// - Linux: Only available with the `gtk3`, `gtk4` and `xdg-portal` backends, `zenity` and `kdialog` always return `None`.
+	/// - Not available on Windows and macOS, always returns `None`.
+	#[inline]
+	pub fn show(&self) -> Option<PrintSettings> {
+		print_dialog(self)
+	}
+}
+
+/// Application chooser dialog.
+///
+/// Lets the user pick an application to open a file with, also known as "Open with...".
+///
+/// ```no_run
+/// let chooser = rustydialogs::AppChooser {
+/// 	title: "Open With",
+/// 	path: std::path::Path::new("export.csv"),
+/// 	mime_type: None,
+/// 	owner: None,
+/// };
+///
+/// if let Some(app) = chooser.show() {
+/// 	println!("Chosen application: {app}");
+/// }
+/// ```
+#[derive(Copy, Clone)]
+pub struct AppChooser<'a> {
+	/// The title of the dialog.
+	pub title: &'a str,
+	/// The file to open.
+	pub path: &'a Path,
+	/// The MIME type of the file (e.g. `"text/csv"`), or `None` to guess it from the file.
+	pub mime_type: Option<&'a str>,
+	/// The owner window of the dialog.
+	pub owner: Option<&'a dyn HasWindowHandle>,
+}
+
+impl<'a> AppChooser<'a> {
+	/// Show the dialog.
+	///
+	/// Returns the identifier of the chosen application, or `None` if the user cancelled the dialog.
+	///
+	/// ### Platform-specific behavior
+	///
+	/// - Linux: The identifier is the desktop file id (e.g. `"org.gnome.TextEditor.desktop"`).
+	/// - Linux: `zenity` and `kdialog` list the installed `.desktop` entries registered for the MIME type.
+	/// - Linux: Not available with the `xdg-portal` backend, always returns `None`, use [`open`](Self::open) instead.
+	/// - macOS: The identifier is the path of the application bundle.
+	/// - Not available on Windows, always returns `None`, use [`open`](Self::open) instead.
+	#[inline]
+	pub fn show(&self) -> Option<String> {
+		app_chooser(self)
+	}
+
+	/// Show the dialog and open the file with the chosen application.
+	///
+	/// Returns `true` if the application was launched.
+	///
+	/// ### Platform-specific behavior
+	///
+	/// - Linux: The `xdg-portal` backend asks through the OpenURI portal, which launches the application itself.
+	/// - Windows: Shows the shell "Open with" dialog.
+	#[inline]
+	pub fn open(&self) -> bool {
+		app_chooser_open(self)
+	}
+}
+
+/// Icon context, the kind of themed icons to choose from.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum IconContext {
+	/// All icons.
+	Any,
+	/// Icons for actions, e.g. `"document-save"`.
+	Action,
+	/// Application icons.
+	Application,
+	/// Icons for menu categories.
+	Category,
+	/// Icons for hardware devices.
+	Device,
+	/// Emblems shown on top of other icons.
+	Emblem,
+	/// Emoticons.
+	Emote,
+	/// Icons for file types.
+	MimeType,
+	/// Icons for places, e.g. `"folder"`.
+	Place,
+	/// Status icons.
+	Status,
+}
+
+/// Icon returned by the icon picker.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IconValue {
+	/// A themed icon name, e.g. `"accessories-text-editor"`.
+	Name(String),
+	/// An image file.
+	File(PathBuf),
+}
+
+/// Icon picker dialog.
+///
+/// ```no_run
+/// let icon = rustydialogs::IconPicker {
+/// 	title: "Choose Icon",
+/// 	value: "utilities-terminal",
+/// 	context: rustydialogs::IconContext::Application,
+/// 	owner: None,
+/// }.show();
+///
+/// match icon {
+/// 	Some(rustydialogs::IconValue::Name(name)) => println!("Themed icon: {name}"),
+/// 	Some(rustydialogs::IconValue::File(path)) => println!("Icon file: {}", path.display()),
+/// 	None => println!("Canceled"),
+/// }
+/// ```
+#[derive(Copy, Clone)]
+pub struct IconPicker<'a> {
+	/// The title of the dialog.
+	pub title: &'a str,
+	/// The themed icon name to select initially, or empty.
+	pub value: &'a str,
+	/// The kind of themed icons to choose from.
+	pub context: IconContext,
+	/// The owner window of the dialog.
+	pub owner: Option<&'a dyn HasWindowHandle>,
+}
+
+impl<'a> IconPicker<'a> {
+	/// Show the dialog.
+	///
+	/// Returns `Some(IconValue)` if the user picked an icon, or `None` if the user cancelled the dialog.
+	///
+	/// ### Platform-specific behavior
+	///
+	/// - Linux: `kdialog` shows its icon dialog, which can also browse for an image file. The initial value is ignored.
+	/// - Linux: `gtk3`, `gtk4` and `zenity` list the icons found in the icon theme directories, with a button to browse for an image file instead.
+	/// - Linux: `zenity` cannot preselect the initial value.
+	/// - Linux: The `xdg-portal` backend asks for an image file.
+	/// - Windows and macOS: There are no themed icons, asks for an image file instead.
+	#[inline]
+	pub fn show(&self) -> Option<IconValue> {
+		icon_picker(self)
 	}
 }
 
@@ -405,6 +1254,68 @@ impl<'a> Notification<'a> {
 	}
 }
 
+/// Preferred color scheme of the desktop.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum ColorScheme {
+	/// No preference, usually rendered as light.
+	#[default]
+	NoPreference,
+	/// Prefers light colors.
+	Light,
+	/// Prefers dark colors.
+	Dark,
+}
+
+/// Desktop appearance settings.
+///
+/// ```no_run
+/// let appearance = rustydialogs::appearance();
+///
+/// if appearance.color_scheme == rustydialogs::ColorScheme::Dark {
+/// 	println!("Dark mode, accent color {:?}", appearance.accent_color);
+/// }
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Appearance {
+	/// The preferred color scheme.
+	pub color_scheme: ColorScheme,
+	/// The accent color, if the desktop has one.
+	pub accent_color: Option<ColorValue>,
+	/// Whether high contrast is requested.
+	pub high_contrast: bool,
+}
+
+/// Query the appearance settings of the desktop.
+///
+/// Settings which cannot be determined are left at their default values.
+///
+/// ### Platform-specific behavior
+///
+/// - Windows: Reads the app light theme and accent color from the registry and the high contrast mode from the system parameters.
+/// - Linux: With the `xdg-portal` feature the settings portal is asked first.
+///   Otherwise the `gtk3` and `gtk4` backends read `gtk-application-prefer-dark-theme` and the theme name, and other backends read the GNOME settings with `gsettings`.
+///   The accent color is only available from the settings portal.
+/// - macOS: Reads the interface style, accent color and increased contrast from the user defaults.
+#[inline]
+pub fn appearance() -> Appearance {
+	desktop_appearance()
+}
+
+/// Force the color scheme of dialogs shown from now on, e.g. to match a dark mode toggle of the application.
+///
+/// The default [`ColorScheme::NoPreference`] follows the desktop setting.
+/// This does not change what [`appearance`] returns.
+///
+/// ### Platform-specific behavior
+///
+/// - Linux: Supported by the `gtk3`, `gtk4` and `zenity` backends.
+///   GTK backends set `gtk-application-prefer-dark-theme`, zenity is started with the `GTK_THEME` and `ADW_DEBUG_COLOR_SCHEME` environment variables.
+/// - Windows and macOS: Not supported, dialogs follow the system setting.
+#[inline]
+pub fn set_color_scheme(color_scheme: ColorScheme) {
+	utils::set_color_scheme(color_scheme)
+}
+
 #[cfg(windows)]
 mod win32;
 #[cfg(windows)]
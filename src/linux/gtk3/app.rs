@@ -0,0 +1,52 @@
+use super::*;
+
+pub fn app_chooser(p: &AppChooser<'_>) -> Option<String> {
+	ensure_gtk_initialized();
+
+	let dialog = match p.mime_type {
+		Some(mime_type) => {
+			let content_type = cstring(mime_type);
+			unsafe { gtk_sys::gtk_app_chooser_dialog_new_for_content_type(ptr::null_mut(), gtk_sys::GTK_DIALOG_MODAL, content_type.as_ptr()) }
+		}
+		None => unsafe {
+			let path = cstring(&p.path.to_string_lossy());
+			let file = gio_sys::g_file_new_for_path(path.as_ptr());
+			let dialog = gtk_sys::gtk_app_chooser_dialog_new(ptr::null_mut(), gtk_sys::GTK_DIALOG_MODAL, file);
+			g_object_unref(file as *mut _);
+			dialog
+		},
+	};
+
+	let title = cstring(p.title);
+	unsafe {
+		gtk_sys::gtk_window_set_title(dialog as *mut gtk_sys::GtkWindow, title.as_ptr());
+	}
+
+	let response = unsafe { gtk_sys::gtk_dialog_run(dialog as *mut gtk_sys::GtkDialog) };
+	let id = if response == gtk_sys::GTK_RESPONSE_OK {
+		unsafe { app_id(gtk_sys::gtk_app_chooser_get_app_info(dialog as *mut gtk_sys::GtkAppChooser)) }
+	}
+	else {
+		None
+	};
+
+	unsafe {
+		gtk_sys::gtk_widget_destroy(dialog);
+		while gtk_sys::gtk_events_pending() != 0 {
+			gtk_sys::gtk_main_iteration();
+		}
+	}
+
+	id
+}
+
+unsafe fn app_id(app_info: *mut gio_sys::GAppInfo) -> Option<String> {
+	if app_info.is_null() {
+		return None;
+	}
+
+	let id = gio_sys::g_app_info_get_id(app_info);
+	let id = if id.is_null() { None } else { Some(CStr::from_ptr(id).to_string_lossy().into_owned()) };
+	g_object_unref(app_info as *mut _);
+	id
+}
@@ -6,14 +6,12 @@ pub fn color_picker(p: &ColorPicker<'_>) -> Option<ColorValue> {
 	let title = cstring(p.title);
 	let dialog = unsafe { gtk_sys::gtk_color_chooser_dialog_new(title.as_ptr(), ptr::null_mut()) };
 
-	let mut rgba = gdk_sys::GdkRGBA {
-		red: f64::from(p.value.red) / 255.0,
-		green: f64::from(p.value.green) / 255.0,
-		blue: f64::from(p.value.blue) / 255.0,
-		alpha: 1.0,
-	};
+	let mut rgba = gdk_rgba(p.value, p.alpha);
 
 	unsafe {
+		gtk_sys::gtk_color_chooser_set_use_alpha(dialog as *mut gtk_sys::GtkColorChooser, p.alpha as i32);
+		add_palette(dialog as *mut gtk_sys::GtkColorChooser, p.palette, p.alpha);
+		add_palette(dialog as *mut gtk_sys::GtkColorChooser, &utils::recent_colors(p.key), p.alpha);
 		gtk_sys::gtk_color_chooser_set_rgba(dialog as *mut gtk_sys::GtkColorChooser, &rgba);
 	}
 
@@ -40,5 +38,24 @@ pub fn color_picker(p: &ColorPicker<'_>) -> Option<ColorValue> {
 		red: (rgba.red.clamp(0.0, 1.0) * 255.0).round() as u8,
 		green: (rgba.green.clamp(0.0, 1.0) * 255.0).round() as u8,
 		blue: (rgba.blue.clamp(0.0, 1.0) * 255.0).round() as u8,
+		alpha: if p.alpha { (rgba.alpha.clamp(0.0, 1.0) * 255.0).round() as u8 } else { 255 },
 	})
 }
+
+fn gdk_rgba(color: ColorValue, alpha: bool) -> gdk_sys::GdkRGBA {
+	gdk_sys::GdkRGBA {
+		red: f64::from(color.red) / 255.0,
+		green: f64::from(color.green) / 255.0,
+		blue: f64::from(color.blue) / 255.0,
+		alpha: if alpha { f64::from(color.alpha) / 255.0 } else { 1.0 },
+	}
+}
+
+// Adding the first palette replaces the default palette.
+unsafe fn add_palette(chooser: *mut gtk_sys::GtkColorChooser, colors: &[ColorValue], alpha: bool) {
+	if colors.is_empty() {
+		return;
+	}
+	let mut colors = colors.iter().map(|&color| gdk_rgba(color, alpha)).collect::<Vec<_>>();
+	gtk_sys::gtk_color_chooser_add_palette(chooser, gtk_sys::GTK_ORIENTATION_HORIZONTAL, 8, colors.len() as i32, colors.as_mut_ptr());
+}
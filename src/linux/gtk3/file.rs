@@ -1,23 +1,76 @@
 use super::*;
 
-fn apply_file_chooser_defaults(dialog: *mut gtk_sys::GtkFileChooser, p: &FileDialog<'_>, save: bool, multiple: bool) {
+// Returns the added filters in the order of [`utils::dialog_filters`].
+fn apply_file_chooser_defaults(dialog: *mut gtk_sys::GtkFileChooser, p: &FileDialog<'_>, save: bool, multiple: bool) -> Vec<*mut gtk_sys::GtkFileFilter> {
+	let mut gtk_filters = Vec::new();
 	unsafe {
 		gtk_sys::gtk_file_chooser_set_select_multiple(dialog, multiple as i32);
+		gtk_sys::gtk_file_chooser_set_show_hidden(dialog, p.show_hidden as i32);
+		gtk_sys::gtk_file_chooser_set_local_only(dialog, p.local_only as i32);
 		if save {
-			gtk_sys::gtk_file_chooser_set_do_overwrite_confirmation(dialog, 1);
+			gtk_sys::gtk_file_chooser_set_do_overwrite_confirmation(dialog, p.confirm_overwrite as i32);
+			gtk_sys::gtk_file_chooser_set_create_folders(dialog, p.create_folders as i32);
 		}
 
-		if let Some(filters) = p.filters {
-			for filter in filters {
-				add_filter(dialog, filter);
-			}
-			add_filter(dialog, &FileFilter::ALL_FILES);
+		for filter in &utils::dialog_filters(p) {
+			gtk_filters.push(add_filter(dialog, filter));
 		}
+		if let Some(default) = utils::default_filter(p) {
+			gtk_sys::gtk_file_chooser_set_filter(dialog, gtk_filters[default]);
+		}
+	}
+	for choice in p.choices {
+		add_choice(dialog, choice);
 	}
 
 	if let Some(path) = utils::abspath(p.path) {
 		apply_initial_path(dialog, path.as_ref());
 	}
+	gtk_filters
+}
+
+fn selected_filter(dialog: *mut gtk_sys::GtkFileChooser, p: &FileDialog<'_>, gtk_filters: &[*mut gtk_sys::GtkFileFilter]) -> Option<usize> {
+	let selected = unsafe { gtk_sys::gtk_file_chooser_get_filter(dialog) };
+	if selected.is_null() {
+		return None;
+	}
+	let index = gtk_filters.iter().position(|&gtk_filter| gtk_filter == selected)?;
+	utils::filter_index(p, index)
+}
+
+fn selection(dialog: *mut gtk_sys::GtkFileChooser, p: &FileDialog<'_>, gtk_filters: &[*mut gtk_sys::GtkFileFilter]) -> FileSelection {
+	let mut selection = utils::file_selection(p, selected_filter(dialog, p, gtk_filters));
+	for (id, option) in &mut selection.choices {
+		let c_id = cstring(id);
+		let value = unsafe { gtk_sys::gtk_file_chooser_get_choice(dialog, c_id.as_ptr()) };
+		if !value.is_null() {
+			*option = unsafe { CStr::from_ptr(value) }.to_string_lossy().into_owned();
+		}
+	}
+	selection
+}
+
+fn add_choice(dialog: *mut gtk_sys::GtkFileChooser, choice: &FileChoice<'_>) {
+	let id = cstring(choice.id);
+	let label = cstring(choice.label);
+	let default = cstring(choice.default);
+
+	// The option ids and labels are null terminated arrays, a choice without options is a checkbox.
+	let option_ids = choice.options.iter().map(|&(id, _)| cstring(id)).collect::<Vec<_>>();
+	let option_labels = choice.options.iter().map(|&(_, label)| cstring(label)).collect::<Vec<_>>();
+	let mut option_id_ptrs = option_ids.iter().map(|id| id.as_ptr()).chain(Some(ptr::null())).collect::<Vec<_>>();
+	let mut option_label_ptrs = option_labels.iter().map(|label| label.as_ptr()).chain(Some(ptr::null())).collect::<Vec<_>>();
+	let (options, labels) = if choice.options.is_empty() {
+		(ptr::null_mut(), ptr::null_mut())
+	}
+	else {
+		(option_id_ptrs.as_mut_ptr(), option_label_ptrs.as_mut_ptr())
+	};
+
+	unsafe {
+		gtk_sys::gtk_file_chooser_add_choice(dialog, id.as_ptr(), label.as_ptr(), options, labels);
+		gtk_sys::gtk_file_chooser_set_choice(dialog, id.as_ptr(), default.as_ptr());
+	}
 }
 
 fn apply_initial_path(dialog: *mut gtk_sys::GtkFileChooser, path: &Path) {
@@ -48,33 +101,84 @@ fn apply_initial_path(dialog: *mut gtk_sys::GtkFileChooser, path: &Path) {
 	}
 }
 
-fn add_filter(dialog: *mut gtk_sys::GtkFileChooser, filter: &FileFilter) {
+fn add_filter(dialog: *mut gtk_sys::GtkFileChooser, filter: &FileFilter) -> *mut gtk_sys::GtkFileFilter {
 	unsafe {
 		let gtk_filter = gtk_sys::gtk_file_filter_new();
 		let name = cstring(filter.name);
 		gtk_sys::gtk_file_filter_set_name(gtk_filter, name.as_ptr());
-		for pattern in filter.patterns {
-			let pattern = cstring(pattern);
+		for pattern in utils::glob_patterns(filter.patterns.iter().copied(), utils::GlobSyntax::CaseSensitive) {
+			let pattern = cstring(&pattern);
 			gtk_sys::gtk_file_filter_add_pattern(gtk_filter, pattern.as_ptr());
 		}
+		for mime_type in filter.mime_types {
+			let mime_type = cstring(mime_type);
+			gtk_sys::gtk_file_filter_add_mime_type(gtk_filter, mime_type.as_ptr());
+		}
 		gtk_sys::gtk_file_chooser_add_filter(dialog, gtk_filter);
+		gtk_filter
 	}
 }
 
-pub fn pick_file(p: &FileDialog<'_>) -> Option<PathBuf> {
-	pick_files_impl(p, false).and_then(|files| files.into_iter().next())
+pub fn pick_file(p: &FileDialog<'_>) -> Option<(PathBuf, FileSelection)> {
+	let (files, filter) = pick_files_impl(p, false)?;
+	Some((files.into_iter().next()?, filter))
 }
 
-pub fn pick_files(p: &FileDialog<'_>) -> Option<Vec<PathBuf>> {
+pub fn pick_files(p: &FileDialog<'_>) -> Option<(Vec<PathBuf>, FileSelection)> {
 	pick_files_impl(p, true)
 }
 
-fn pick_files_impl(p: &FileDialog<'_>, multiple: bool) -> Option<Vec<PathBuf>> {
+fn pick_files_impl(p: &FileDialog<'_>, multiple: bool) -> Option<(Vec<PathBuf>, FileSelection)> {
+	run_open_dialog(p, multiple, chooser_filenames)
+}
+
+pub fn pick_uri(p: &FileDialog<'_>) -> Option<String> {
+	let (uris, _) = run_open_dialog(p, false, chooser_uris)?;
+	uris.into_iter().next()
+}
+
+pub fn pick_uris(p: &FileDialog<'_>) -> Option<Vec<String>> {
+	run_open_dialog(p, true, chooser_uris).map(|(uris, _)| uris)
+}
+
+fn chooser_filenames(chooser: *mut gtk_sys::GtkFileChooser, multiple: bool) -> Vec<PathBuf> {
+	if multiple {
+		let list = unsafe { gtk_sys::gtk_file_chooser_get_filenames(chooser) };
+		if list.is_null() {
+			Vec::new()
+		} else {
+			collect_file_list(list)
+		}
+	} else {
+		let filename = unsafe { gtk_sys::gtk_file_chooser_get_filename(chooser) };
+		c_to_path_buf(filename).into_iter().collect()
+	}
+}
+
+fn chooser_uris(chooser: *mut gtk_sys::GtkFileChooser, multiple: bool) -> Vec<String> {
+	if multiple {
+		let list = unsafe { gtk_sys::gtk_file_chooser_get_uris(chooser) };
+		if list.is_null() {
+			Vec::new()
+		} else {
+			collect_uri_list(list)
+		}
+	} else {
+		let uri = unsafe { gtk_sys::gtk_file_chooser_get_uri(chooser) };
+		c_to_string(uri).into_iter().collect()
+	}
+}
+
+fn run_open_dialog<T>(
+	p: &FileDialog<'_>,
+	multiple: bool,
+	selected: fn(*mut gtk_sys::GtkFileChooser, bool) -> Vec<T>,
+) -> Option<(Vec<T>, FileSelection)> {
 	ensure_gtk_initialized();
 
 	let title = cstring(p.title);
-	let accept = c"Open";
-	let cancel = c"Cancel";
+	let accept = cstring(p.accept_label.unwrap_or("Open"));
+	let cancel = cstring(p.cancel_label.unwrap_or("Cancel"));
 
 	let native = unsafe {
 		gtk_sys::gtk_file_chooser_native_new(
@@ -87,7 +191,7 @@ fn pick_files_impl(p: &FileDialog<'_>, multiple: bool) -> Option<Vec<PathBuf>> {
 	};
 	let chooser = native as *mut gtk_sys::GtkFileChooser;
 
-	apply_file_chooser_defaults(chooser, p, false, multiple);
+	let gtk_filters = apply_file_chooser_defaults(chooser, p, false, multiple);
 
 	let response = run_native_dialog(native as *mut gtk_sys::GtkNativeDialog);
 	if response != gtk_sys::GTK_RESPONSE_ACCEPT {
@@ -95,28 +199,26 @@ fn pick_files_impl(p: &FileDialog<'_>, multiple: bool) -> Option<Vec<PathBuf>> {
 		return None;
 	}
 
-	let result = if multiple {
-		let list = unsafe { gtk_sys::gtk_file_chooser_get_filenames(chooser) };
-		if list.is_null() {
-			Vec::new()
-		} else {
-			collect_file_list(list)
-		}
-	} else {
-		let filename = unsafe { gtk_sys::gtk_file_chooser_get_filename(chooser) };
-		c_to_path_buf(filename).into_iter().collect()
-	};
-
+	let result = selected(chooser, multiple);
+	let selection = selection(chooser, p, &gtk_filters);
 	unsafe { g_object_unref(native as *mut _) };
-	Some(result)
+	Some((result, selection))
+}
+
+pub fn save_file(p: &FileDialog<'_>) -> Option<(PathBuf, FileSelection)> {
+	run_save_dialog(p, |chooser| c_to_path_buf(unsafe { gtk_sys::gtk_file_chooser_get_filename(chooser) }))
+}
+
+pub fn save_uri(p: &FileDialog<'_>) -> Option<String> {
+	run_save_dialog(p, |chooser| c_to_string(unsafe { gtk_sys::gtk_file_chooser_get_uri(chooser) })).map(|(uri, _)| uri)
 }
 
-pub fn save_file(p: &FileDialog<'_>) -> Option<PathBuf> {
+fn run_save_dialog<T>(p: &FileDialog<'_>, selected: fn(*mut gtk_sys::GtkFileChooser) -> Option<T>) -> Option<(T, FileSelection)> {
 	ensure_gtk_initialized();
 
 	let title = cstring(p.title);
-	let accept = c"Save";
-	let cancel = c"Cancel";
+	let accept = cstring(p.accept_label.unwrap_or("Save"));
+	let cancel = cstring(p.cancel_label.unwrap_or("Cancel"));
 
 	let native = unsafe {
 		gtk_sys::gtk_file_chooser_native_new(
@@ -129,7 +231,7 @@ pub fn save_file(p: &FileDialog<'_>) -> Option<PathBuf> {
 	};
 	let chooser = native as *mut gtk_sys::GtkFileChooser;
 
-	apply_file_chooser_defaults(chooser, p, true, false);
+	let gtk_filters = apply_file_chooser_defaults(chooser, p, true, false);
 
 	let response = run_native_dialog(native as *mut gtk_sys::GtkNativeDialog);
 	if response != gtk_sys::GTK_RESPONSE_ACCEPT {
@@ -137,8 +239,7 @@ pub fn save_file(p: &FileDialog<'_>) -> Option<PathBuf> {
 		return None;
 	}
 
-	let filename = unsafe { gtk_sys::gtk_file_chooser_get_filename(chooser) };
-	let result = c_to_path_buf(filename);
+	let result = selected(chooser).map(|value| (value, selection(chooser, p, &gtk_filters)));
 
 	unsafe { g_object_unref(native as *mut _) };
 	result
@@ -12,8 +12,8 @@ fn choose_folders_impl(p: &FileDialog<'_>, multiple: bool) -> Option<Vec<PathBuf
 	ensure_gtk_initialized();
 
 	let title = cstring(p.title);
-	let accept = c"Select";
-	let cancel = c"Cancel";
+	let accept = cstring(p.accept_label.unwrap_or("Select"));
+	let cancel = cstring(p.cancel_label.unwrap_or("Cancel"));
 
 	let native = unsafe {
 		gtk_sys::gtk_file_chooser_native_new(
@@ -28,6 +28,9 @@ fn choose_folders_impl(p: &FileDialog<'_>, multiple: bool) -> Option<Vec<PathBuf
 
 	unsafe {
 		gtk_sys::gtk_file_chooser_set_select_multiple(chooser, multiple as i32);
+		gtk_sys::gtk_file_chooser_set_show_hidden(chooser, p.show_hidden as i32);
+		gtk_sys::gtk_file_chooser_set_local_only(chooser, p.local_only as i32);
+		gtk_sys::gtk_file_chooser_set_create_folders(chooser, p.create_folders as i32);
 		if let Some(directory) = p.path {
 			if let Some(c_path) = os_cstring(directory.as_os_str()) {
 				gtk_sys::gtk_file_chooser_set_current_folder(chooser, c_path.as_ptr());
@@ -0,0 +1,56 @@
+use super::*;
+
+pub fn font_picker(p: &FontPicker<'_>) -> Option<FontValue> {
+	ensure_gtk_initialized();
+
+	let title = cstring(p.title);
+	let dialog = unsafe { gtk_sys::gtk_font_chooser_dialog_new(title.as_ptr(), ptr::null_mut()) };
+
+	if !p.value.is_empty() {
+		let value = cstring(p.value);
+		unsafe {
+			gtk_sys::gtk_font_chooser_set_font(dialog as *mut gtk_sys::GtkFontChooser, value.as_ptr());
+		}
+	}
+
+	let response = unsafe { gtk_sys::gtk_dialog_run(dialog as *mut gtk_sys::GtkDialog) };
+	let font = if response == gtk_sys::GTK_RESPONSE_OK {
+		unsafe {
+			let desc = gtk_sys::gtk_font_chooser_get_font_desc(dialog as *mut gtk_sys::GtkFontChooser);
+			font_value(desc)
+		}
+	}
+	else {
+		None
+	};
+
+	unsafe {
+		gtk_sys::gtk_widget_destroy(dialog);
+		while gtk_sys::gtk_events_pending() != 0 {
+			gtk_sys::gtk_main_iteration();
+		}
+	}
+
+	font
+}
+
+unsafe fn font_value(desc: *mut pango_sys::PangoFontDescription) -> Option<FontValue> {
+	if desc.is_null() {
+		return None;
+	}
+
+	let family = pango_sys::pango_font_description_get_family(desc);
+	let family = if family.is_null() { String::new() } else { CStr::from_ptr(family).to_string_lossy().into_owned() };
+
+	let style = match pango_sys::pango_font_description_get_style(desc) {
+		pango_sys::PANGO_STYLE_OBLIQUE => FontStyle::Oblique,
+		pango_sys::PANGO_STYLE_ITALIC => FontStyle::Italic,
+		_ => FontStyle::Normal,
+	};
+	let weight = pango_sys::pango_font_description_get_weight(desc).clamp(100, 1000) as u16;
+	let size = pango_sys::pango_font_description_get_size(desc) as f32 / pango_sys::PANGO_SCALE as f32;
+
+	pango_sys::pango_font_description_free(desc);
+
+	Some(FontValue { family, style, weight, size })
+}
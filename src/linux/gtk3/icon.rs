@@ -0,0 +1,86 @@
+use super::*;
+
+const RESPONSE_BROWSE: i32 = 1;
+
+pub fn icon_picker(p: &IconPicker<'_>) -> Option<IconValue> {
+	ensure_gtk_initialized();
+
+	let names = icons::icon_names(p.context, &settings::icon_theme());
+	let dialog = unsafe { gtk_sys::gtk_dialog_new() };
+	let title = cstring(p.title);
+
+	let (response, selected) = unsafe {
+		gtk_sys::gtk_window_set_title(dialog as *mut gtk_sys::GtkWindow, title.as_ptr());
+		gtk_sys::gtk_window_set_default_size(dialog as *mut gtk_sys::GtkWindow, 560, 480);
+		gtk_sys::gtk_dialog_add_button(dialog as *mut gtk_sys::GtkDialog, c"Browse...".as_ptr(), RESPONSE_BROWSE);
+		gtk_sys::gtk_dialog_add_button(dialog as *mut gtk_sys::GtkDialog, c"Cancel".as_ptr(), gtk_sys::GTK_RESPONSE_CANCEL);
+		gtk_sys::gtk_dialog_add_button(dialog as *mut gtk_sys::GtkDialog, c"OK".as_ptr(), gtk_sys::GTK_RESPONSE_OK);
+		gtk_sys::gtk_dialog_set_default_response(dialog as *mut gtk_sys::GtkDialog, gtk_sys::GTK_RESPONSE_OK);
+
+		let content = gtk_sys::gtk_dialog_get_content_area(dialog as *mut gtk_sys::GtkDialog);
+		gtk_sys::gtk_container_set_border_width(content as *mut gtk_sys::GtkContainer, 14);
+		let scrolled = gtk_sys::gtk_scrolled_window_new(ptr::null_mut(), ptr::null_mut());
+		gtk_sys::gtk_scrolled_window_set_policy(scrolled as *mut gtk_sys::GtkScrolledWindow, gtk_sys::GTK_POLICY_NEVER, gtk_sys::GTK_POLICY_AUTOMATIC);
+
+		// An icon view only renders the visible icons, themes easily have thousands of them.
+		let store = gtk_sys::gtk_list_store_newv(1, [gobject_sys::G_TYPE_STRING].as_mut_ptr());
+		for name in &names {
+			let name = cstring(name);
+			gtk_sys::gtk_list_store_insert_with_values(store, ptr::null_mut(), -1, 0, name.as_ptr(), -1);
+		}
+		let icon_view = gtk_sys::gtk_icon_view_new_with_model(store as *mut gtk_sys::GtkTreeModel);
+		g_object_unref(store as *mut _);
+		gtk_sys::gtk_icon_view_set_selection_mode(icon_view as *mut gtk_sys::GtkIconView, gtk_sys::GTK_SELECTION_SINGLE);
+		// The icon name is shown as tooltip, labels would make the grid too wide.
+		gtk_sys::gtk_icon_view_set_tooltip_column(icon_view as *mut gtk_sys::GtkIconView, 0);
+		let renderer = gtk_sys::gtk_cell_renderer_pixbuf_new();
+		gobject_sys::g_object_set(
+			renderer as *mut gobject_sys::GObject,
+			c"stock-size".as_ptr(),
+			gtk_sys::GTK_ICON_SIZE_DIALOG as u32,
+			ptr::null::<c_char>(),
+		);
+		gtk_sys::gtk_cell_layout_pack_start(icon_view as *mut gtk_sys::GtkCellLayout, renderer, 1);
+		gtk_sys::gtk_cell_layout_add_attribute(icon_view as *mut gtk_sys::GtkCellLayout, renderer, c"icon-name".as_ptr(), 0);
+
+		gtk_sys::gtk_container_add(scrolled as *mut gtk_sys::GtkContainer, icon_view);
+		gtk_sys::gtk_box_pack_start(content as *mut gtk_sys::GtkBox, scrolled, 1, 1, 0);
+		gtk_sys::gtk_widget_show_all(dialog);
+
+		if let Some(index) = names.iter().position(|name| name == p.value) {
+			let path = gtk_sys::gtk_tree_path_new_from_indices(index as i32, -1);
+			gtk_sys::gtk_icon_view_select_path(icon_view as *mut gtk_sys::GtkIconView, path);
+			gtk_sys::gtk_icon_view_scroll_to_path(icon_view as *mut gtk_sys::GtkIconView, path, 1, 0.5, 0.0);
+			gtk_sys::gtk_tree_path_free(path);
+		}
+
+		let response = gtk_sys::gtk_dialog_run(dialog as *mut gtk_sys::GtkDialog);
+		(response, selected_index(icon_view as *mut gtk_sys::GtkIconView))
+	};
+
+	unsafe {
+		gtk_sys::gtk_widget_destroy(dialog);
+		while gtk_sys::gtk_events_pending() != 0 {
+			gtk_sys::gtk_main_iteration();
+		}
+	}
+
+	match response {
+		gtk_sys::GTK_RESPONSE_OK => selected.and_then(|index| names.into_iter().nth(index)).map(IconValue::Name),
+		RESPONSE_BROWSE => utils::pick_icon_file(p.title, p.owner),
+		_ => None,
+	}
+}
+
+unsafe fn selected_index(icon_view: *mut gtk_sys::GtkIconView) -> Option<usize> {
+	let list = gtk_sys::gtk_icon_view_get_selected_items(icon_view);
+	if list.is_null() {
+		return None;
+	}
+	let path = (*list).data as *mut gtk_sys::GtkTreePath;
+	let index = *gtk_sys::gtk_tree_path_get_indices(path);
+	// The selection mode is single, the list holds one path at most.
+	gtk_sys::gtk_tree_path_free(path);
+	glib_sys::g_list_free(list);
+	usize::try_from(index).ok()
+}
@@ -124,3 +124,68 @@ fn text_input_multiline(p: &TextInput<'_>) -> Option<String> {
 		result
 	}
 }
+
+pub fn login_dialog(p: &LoginDialog<'_>) -> Option<Credentials> {
+	ensure_gtk_initialized();
+
+	let dialog = unsafe { gtk_sys::gtk_dialog_new() };
+	let title = cstring(p.title);
+	let message = cstring(p.message);
+	let username = cstring(p.username);
+	let username_placeholder = c"User name";
+	let password_placeholder = c"Password";
+	let ok = c"OK";
+	let cancel = c"Cancel";
+
+	unsafe {
+		gtk_sys::gtk_window_set_title(dialog as *mut gtk_sys::GtkWindow, title.as_ptr());
+		gtk_sys::gtk_window_set_default_size(dialog as *mut gtk_sys::GtkWindow, 520, -1);
+		gtk_sys::gtk_dialog_add_button(dialog as *mut gtk_sys::GtkDialog, cancel.as_ptr(), gtk_sys::GTK_RESPONSE_CANCEL);
+		gtk_sys::gtk_dialog_add_button(dialog as *mut gtk_sys::GtkDialog, ok.as_ptr(), gtk_sys::GTK_RESPONSE_OK);
+		gtk_sys::gtk_dialog_set_default_response(dialog as *mut gtk_sys::GtkDialog, gtk_sys::GTK_RESPONSE_OK);
+
+		let content = gtk_sys::gtk_dialog_get_content_area(dialog as *mut gtk_sys::GtkDialog);
+		style_dialog_layout(dialog);
+		let label = gtk_sys::gtk_label_new(message.as_ptr());
+		gtk_sys::gtk_label_set_xalign(label as *mut gtk_sys::GtkLabel, 0.0);
+		let username_entry = gtk_sys::gtk_entry_new();
+		gtk_sys::gtk_entry_set_text(username_entry as *mut gtk_sys::GtkEntry, username.as_ptr());
+		gtk_sys::gtk_entry_set_placeholder_text(username_entry as *mut gtk_sys::GtkEntry, username_placeholder.as_ptr());
+		gtk_sys::gtk_entry_set_activates_default(username_entry as *mut gtk_sys::GtkEntry, 1);
+		let password_entry = gtk_sys::gtk_entry_new();
+		gtk_sys::gtk_entry_set_placeholder_text(password_entry as *mut gtk_sys::GtkEntry, password_placeholder.as_ptr());
+		gtk_sys::gtk_entry_set_visibility(password_entry as *mut gtk_sys::GtkEntry, 0);
+		gtk_sys::gtk_entry_set_activates_default(password_entry as *mut gtk_sys::GtkEntry, 1);
+
+		gtk_sys::gtk_box_pack_start(content as *mut gtk_sys::GtkBox, label, 0, 0, 0);
+		gtk_sys::gtk_box_pack_start(content as *mut gtk_sys::GtkBox, username_entry, 0, 0, 0);
+		gtk_sys::gtk_box_pack_start(content as *mut gtk_sys::GtkBox, password_entry, 0, 0, 0);
+		gtk_sys::gtk_widget_show_all(dialog);
+		if !p.username.is_empty() {
+			gtk_sys::gtk_widget_grab_focus(password_entry);
+		}
+
+		let response = gtk_sys::gtk_dialog_run(dialog as *mut gtk_sys::GtkDialog);
+		let result = if response == gtk_sys::GTK_RESPONSE_OK {
+			let username_ptr = gtk_sys::gtk_entry_get_text(username_entry as *mut gtk_sys::GtkEntry);
+			let password_ptr = gtk_sys::gtk_entry_get_text(password_entry as *mut gtk_sys::GtkEntry);
+			if username_ptr.is_null() || password_ptr.is_null() {
+				None
+			} else {
+				Some(Credentials {
+					username: CStr::from_ptr(username_ptr).to_string_lossy().to_string(),
+					password: CStr::from_ptr(password_ptr).to_string_lossy().to_string(),
+				})
+			}
+		} else {
+			None
+		};
+
+		gtk_sys::gtk_widget_destroy(dialog);
+		while gtk_sys::gtk_events_pending() != 0 {
+			gtk_sys::gtk_main_iteration();
+		}
+
+		result
+	}
+}
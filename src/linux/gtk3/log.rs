@@ -0,0 +1,99 @@
+use super::*;
+
+pub struct LogWindowImpl {
+	dialog: *mut gtk_sys::GtkWidget,
+	text_view: *mut gtk_sys::GtkWidget,
+}
+
+impl LogWindowImpl {
+	pub fn new(title: &str) -> LogWindowImpl {
+		ensure_gtk_initialized();
+
+		let dialog = unsafe { gtk_sys::gtk_dialog_new() };
+		let title = cstring(title);
+		let close = c"Close";
+
+		let text_view = unsafe {
+			gtk_sys::gtk_window_set_title(dialog as *mut gtk_sys::GtkWindow, title.as_ptr());
+			gtk_sys::gtk_window_set_default_size(dialog as *mut gtk_sys::GtkWindow, 640, 480);
+			// The dialog keeps itself alive when deleted, hide the close button until the job is finished
+			gtk_sys::gtk_window_set_deletable(dialog as *mut gtk_sys::GtkWindow, 0);
+			gtk_sys::gtk_dialog_add_button(dialog as *mut gtk_sys::GtkDialog, close.as_ptr(), gtk_sys::GTK_RESPONSE_CLOSE);
+			gtk_sys::gtk_dialog_set_response_sensitive(dialog as *mut gtk_sys::GtkDialog, gtk_sys::GTK_RESPONSE_CLOSE, 0);
+
+			let content = gtk_sys::gtk_dialog_get_content_area(dialog as *mut gtk_sys::GtkDialog);
+			gtk_sys::gtk_container_set_border_width(content as *mut gtk_sys::GtkContainer, 14);
+			let scrolled = gtk_sys::gtk_scrolled_window_new(ptr::null_mut(), ptr::null_mut());
+			let text_view = gtk_sys::gtk_text_view_new();
+			gtk_sys::gtk_text_view_set_editable(text_view as *mut gtk_sys::GtkTextView, 0);
+			gtk_sys::gtk_text_view_set_cursor_visible(text_view as *mut gtk_sys::GtkTextView, 0);
+			gtk_sys::gtk_text_view_set_monospace(text_view as *mut gtk_sys::GtkTextView, 1);
+
+			gtk_sys::gtk_widget_set_size_request(scrolled, 480, 280);
+			gtk_sys::gtk_container_add(scrolled as *mut gtk_sys::GtkContainer, text_view);
+			gtk_sys::gtk_box_pack_start(content as *mut gtk_sys::GtkBox, scrolled, 1, 1, 0);
+
+			gtk_sys::gtk_widget_show_all(dialog);
+			text_view
+		};
+
+		pump_events();
+		LogWindowImpl { dialog, text_view }
+	}
+
+	pub fn append_line(&mut self, line: &str) {
+		let line = cstring(line);
+		unsafe {
+			let text_view = self.text_view as *mut gtk_sys::GtkTextView;
+			let buffer = gtk_sys::gtk_text_view_get_buffer(text_view);
+			let mut end = std::mem::zeroed();
+			gtk_sys::gtk_text_buffer_get_end_iter(buffer, &mut end);
+			gtk_sys::gtk_text_buffer_insert(buffer, &mut end, line.as_ptr(), -1);
+			gtk_sys::gtk_text_buffer_insert(buffer, &mut end, c"\n".as_ptr(), -1);
+			gtk_sys::gtk_text_buffer_place_cursor(buffer, &end);
+			gtk_sys::gtk_text_view_scroll_to_mark(text_view, gtk_sys::gtk_text_buffer_get_insert(buffer), 0.0, 0, 0.0, 1.0);
+		}
+		pump_events();
+	}
+
+	pub fn set_title(&mut self, title: &str) {
+		let title = cstring(title);
+		unsafe {
+			gtk_sys::gtk_window_set_title(self.dialog as *mut gtk_sys::GtkWindow, title.as_ptr());
+		}
+		pump_events();
+	}
+
+	pub fn finish(&mut self) {
+		unsafe {
+			gtk_sys::gtk_dialog_set_response_sensitive(self.dialog as *mut gtk_sys::GtkDialog, gtk_sys::GTK_RESPONSE_CLOSE, 1);
+			gtk_sys::gtk_window_set_deletable(self.dialog as *mut gtk_sys::GtkWindow, 1);
+		}
+		pump_events();
+	}
+
+	pub fn wait_closed(self) {
+		unsafe {
+			gtk_sys::gtk_dialog_run(self.dialog as *mut gtk_sys::GtkDialog);
+		}
+	}
+}
+
+impl Drop for LogWindowImpl {
+	fn drop(&mut self) {
+		unsafe {
+			gtk_sys::gtk_widget_destroy(self.dialog);
+		}
+		pump_events();
+	}
+}
+
+// There is no main loop running while the caller works, events are only handled here.
+// The window stays unresponsive between calls, which the LogWindow docs point out.
+fn pump_events() {
+	unsafe {
+		while gtk_sys::gtk_events_pending() != 0 {
+			gtk_sys::gtk_main_iteration();
+		}
+	}
+}
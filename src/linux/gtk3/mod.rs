@@ -7,28 +7,50 @@ use gobject_sys::g_object_unref;
 
 use super::*;
 
+mod about;
+mod app;
 mod color;
 mod file;
 mod folder;
+mod font;
+mod icon;
 mod input;
+mod log;
 mod message;
+mod print;
+mod settings;
+mod viewer;
+
+pub use self::log::LogWindowImpl;
 
 pub fn message_box(p: &MessageBox<'_>) -> Option<MessageResult> {
 	message::show(p)
 }
 
-pub fn pick_file(p: &FileDialog<'_>) -> Option<PathBuf> {
+pub fn pick_file(p: &FileDialog<'_>) -> Option<(PathBuf, FileSelection)> {
 	file::pick_file(p)
 }
 
-pub fn pick_files(p: &FileDialog<'_>) -> Option<Vec<PathBuf>> {
+pub fn pick_files(p: &FileDialog<'_>) -> Option<(Vec<PathBuf>, FileSelection)> {
 	file::pick_files(p)
 }
 
-pub fn save_file(p: &FileDialog<'_>) -> Option<PathBuf> {
+pub fn save_file(p: &FileDialog<'_>) -> Option<(PathBuf, FileSelection)> {
 	file::save_file(p)
 }
 
+pub fn pick_uri(p: &FileDialog<'_>) -> Option<String> {
+	file::pick_uri(p)
+}
+
+pub fn pick_uris(p: &FileDialog<'_>) -> Option<Vec<String>> {
+	file::pick_uris(p)
+}
+
+pub fn save_uri(p: &FileDialog<'_>) -> Option<String> {
+	file::save_uri(p)
+}
+
 pub fn choose_folder(p: &FileDialog<'_>) -> Option<PathBuf> {
 	folder::choose_folder(p)
 }
@@ -41,10 +63,42 @@ pub fn text_input(p: &TextInput<'_>) -> Option<String> {
 	input::text_input(p)
 }
 
+pub fn login_dialog(p: &LoginDialog<'_>) -> Option<Credentials> {
+	input::login_dialog(p)
+}
+
+pub fn text_viewer(p: &TextViewer<'_>) -> bool {
+	viewer::text_viewer(p)
+}
+
+pub fn about_dialog(p: &AboutDialog<'_>) {
+	about::about_dialog(p)
+}
+
 pub fn color_picker(p: &ColorPicker<'_>) -> Option<ColorValue> {
 	color::color_picker(p)
 }
 
+pub fn font_picker(p: &FontPicker<'_>) -> Option<FontValue> {
+	font::font_picker(p)
+}
+
+pub fn print_dialog(p: &PrintDialog<'_>) -> Option<PrintSettings> {
+	print::print_dialog(p)
+}
+
+pub fn app_chooser(p: &AppChooser<'_>) -> Option<String> {
+	app::app_chooser(p)
+}
+
+pub fn icon_picker(p: &IconPicker<'_>) -> Option<IconValue> {
+	icon::icon_picker(p)
+}
+
+pub fn appearance() -> Appearance {
+	settings::appearance()
+}
+
 static GTK_INITIALIZED: sync::OnceLock<bool> = sync::OnceLock::new();
 
 fn ensure_gtk_initialized() {
@@ -52,6 +106,7 @@ fn ensure_gtk_initialized() {
 	if !ok {
 		panic!("Failed to initialize GTK3 backend. Ensure a graphical session is available.");
 	}
+	settings::apply_color_scheme();
 }
 
 fn cstring(value: &str) -> CString {
@@ -93,16 +148,29 @@ fn run_native_dialog(dialog: *mut gtk_sys::GtkNativeDialog) -> i32 {
 	response
 }
 
+fn c_to_string(ptr: *mut c_char) -> Option<String> {
+	if ptr.is_null() {
+		return None;
+	}
+	let value = unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() };
+	unsafe { g_free(ptr as *mut _) };
+	Some(value)
+}
+
 fn collect_file_list(list: *mut GSList) -> Vec<PathBuf> {
+	collect_string_list(list, c_to_path_buf)
+}
+
+fn collect_uri_list(list: *mut GSList) -> Vec<String> {
+	collect_string_list(list, c_to_string)
+}
+
+// Takes ownership of the list and its strings.
+fn collect_string_list<T>(list: *mut GSList, convert: fn(*mut c_char) -> Option<T>) -> Vec<T> {
 	let mut result = Vec::new();
 	let mut node = list;
 	while !node.is_null() {
-		let filename_ptr = unsafe { (*node).data as *mut c_char };
-		if !filename_ptr.is_null() {
-			let bytes = unsafe { CStr::from_ptr(filename_ptr).to_bytes() };
-			result.push(PathBuf::from(OsStr::from_bytes(bytes)));
-			unsafe { g_free(filename_ptr as *mut _) };
-		}
+		result.extend(convert(unsafe { (*node).data as *mut c_char }));
 		node = unsafe { (*node).next };
 	}
 	unsafe { g_slist_free(list) };
@@ -0,0 +1,92 @@
+use std::slice;
+
+use super::*;
+
+// The unix print dialog lives in gtk/gtkunixprint.h which gtk-sys does not cover.
+extern "C" {
+	fn gtk_print_unix_dialog_new(title: *const c_char, parent: *mut gtk_sys::GtkWindow) -> *mut gtk_sys::GtkWidget;
+	fn gtk_print_unix_dialog_set_embed_page_setup(dialog: *mut gtk_sys::GtkWidget, embed: glib_sys::gboolean);
+	fn gtk_print_unix_dialog_get_settings(dialog: *mut gtk_sys::GtkWidget) -> *mut gtk_sys::GtkPrintSettings;
+	fn gtk_print_unix_dialog_get_page_setup(dialog: *mut gtk_sys::GtkWidget) -> *mut gtk_sys::GtkPageSetup;
+}
+
+pub fn print_dialog(p: &PrintDialog<'_>) -> Option<PrintSettings> {
+	ensure_gtk_initialized();
+
+	let title = cstring(p.title);
+	let dialog = unsafe { gtk_print_unix_dialog_new(title.as_ptr(), ptr::null_mut()) };
+	unsafe { gtk_print_unix_dialog_set_embed_page_setup(dialog, glib_sys::GTRUE) };
+
+	let response = unsafe { gtk_sys::gtk_dialog_run(dialog as *mut gtk_sys::GtkDialog) };
+	let settings = if response == gtk_sys::GTK_RESPONSE_OK {
+		unsafe { print_settings(gtk_print_unix_dialog_get_settings(dialog), gtk_print_unix_dialog_get_page_setup(dialog)) }
+	}
+	else {
+		None
+	};
+
+	unsafe {
+		gtk_sys::gtk_widget_destroy(dialog);
+		while gtk_sys::gtk_events_pending() != 0 {
+			gtk_sys::gtk_main_iteration();
+		}
+	}
+
+	settings
+}
+
+unsafe fn print_settings(settings: *mut gtk_sys::GtkPrintSettings, page_setup: *mut gtk_sys::GtkPageSetup) -> Option<PrintSettings> {
+	if settings.is_null() {
+		return None;
+	}
+
+	let printer = gtk_sys::gtk_print_settings_get_printer(settings);
+	let printer = if printer.is_null() { String::new() } else { CStr::from_ptr(printer).to_string_lossy().into_owned() };
+	let copies = gtk_sys::gtk_print_settings_get_n_copies(settings).max(1) as u32;
+
+	let mut page_ranges = Vec::new();
+	if gtk_sys::gtk_print_settings_get_print_pages(settings) == gtk_sys::GTK_PRINT_PAGES_RANGES {
+		let mut count = 0;
+		let ranges = gtk_sys::gtk_print_settings_get_page_ranges(settings, &mut count);
+		if !ranges.is_null() {
+			for range in slice::from_raw_parts(ranges, count.max(0) as usize) {
+				let first = range.start.max(0) as u32;
+				page_ranges.push((first + 1, (range.end.max(0) as u32).max(first) + 1));
+			}
+			g_free(ranges as *mut _);
+		}
+	}
+
+	g_object_unref(settings as *mut _);
+
+	let mut result = PrintSettings {
+		printer,
+		copies,
+		page_ranges,
+		paper: String::new(),
+		paper_width: 0.0,
+		paper_height: 0.0,
+		orientation: PageOrientation::Portrait,
+		token: None,
+	};
+
+	if !page_setup.is_null() {
+		let size = gtk_sys::gtk_page_setup_get_paper_size(page_setup);
+		if !size.is_null() {
+			let name = gtk_sys::gtk_paper_size_get_name(size);
+			if !name.is_null() {
+				result.paper = CStr::from_ptr(name).to_string_lossy().into_owned();
+			}
+			result.paper_width = gtk_sys::gtk_paper_size_get_width(size, gtk_sys::GTK_UNIT_MM);
+			result.paper_height = gtk_sys::gtk_paper_size_get_height(size, gtk_sys::GTK_UNIT_MM);
+		}
+		result.orientation = match gtk_sys::gtk_page_setup_get_orientation(page_setup) {
+			gtk_sys::GTK_PAGE_ORIENTATION_LANDSCAPE => PageOrientation::Landscape,
+			gtk_sys::GTK_PAGE_ORIENTATION_REVERSE_PORTRAIT => PageOrientation::ReversePortrait,
+			gtk_sys::GTK_PAGE_ORIENTATION_REVERSE_LANDSCAPE => PageOrientation::ReverseLandscape,
+			_ => PageOrientation::Portrait,
+		};
+	}
+
+	Some(result)
+}
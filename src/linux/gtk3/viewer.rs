@@ -0,0 +1,68 @@
+use super::*;
+
+pub fn text_viewer(p: &TextViewer<'_>) -> bool {
+	let Some(text) = p.content.load() else {
+		return false;
+	};
+
+	ensure_gtk_initialized();
+
+	let dialog = unsafe { gtk_sys::gtk_dialog_new() };
+	let title = cstring(p.title);
+	let text = cstring(&text);
+	let ok = c"OK";
+	let cancel = c"Cancel";
+
+	unsafe {
+		gtk_sys::gtk_window_set_title(dialog as *mut gtk_sys::GtkWindow, title.as_ptr());
+		gtk_sys::gtk_window_set_default_size(dialog as *mut gtk_sys::GtkWindow, 640, 480);
+		gtk_sys::gtk_dialog_add_button(dialog as *mut gtk_sys::GtkDialog, cancel.as_ptr(), gtk_sys::GTK_RESPONSE_CANCEL);
+		let ok_button = gtk_sys::gtk_dialog_add_button(dialog as *mut gtk_sys::GtkDialog, ok.as_ptr(), gtk_sys::GTK_RESPONSE_OK);
+
+		let content = gtk_sys::gtk_dialog_get_content_area(dialog as *mut gtk_sys::GtkDialog);
+		gtk_sys::gtk_box_set_spacing(content as *mut gtk_sys::GtkBox, 10);
+		gtk_sys::gtk_container_set_border_width(content as *mut gtk_sys::GtkContainer, 14);
+		let scrolled = gtk_sys::gtk_scrolled_window_new(ptr::null_mut(), ptr::null_mut());
+		let text_view = gtk_sys::gtk_text_view_new();
+		gtk_sys::gtk_text_view_set_editable(text_view as *mut gtk_sys::GtkTextView, 0);
+		gtk_sys::gtk_text_view_set_cursor_visible(text_view as *mut gtk_sys::GtkTextView, 0);
+		gtk_sys::gtk_text_view_set_wrap_mode(text_view as *mut gtk_sys::GtkTextView, gtk_sys::GTK_WRAP_WORD_CHAR);
+		let buffer = gtk_sys::gtk_text_view_get_buffer(text_view as *mut gtk_sys::GtkTextView);
+		gtk_sys::gtk_text_buffer_set_text(buffer, text.as_ptr(), -1);
+
+		gtk_sys::gtk_widget_set_size_request(scrolled, 480, 280);
+		gtk_sys::gtk_container_add(scrolled as *mut gtk_sys::GtkContainer, text_view);
+		gtk_sys::gtk_box_pack_start(content as *mut gtk_sys::GtkBox, scrolled, 1, 1, 0);
+
+		let checkbox = match p.checkbox {
+			Some(label) => {
+				let label = cstring(label);
+				let checkbox = gtk_sys::gtk_check_button_new_with_label(label.as_ptr());
+				gtk_sys::gtk_box_pack_start(content as *mut gtk_sys::GtkBox, checkbox, 0, 0, 0);
+				// The OK button is only sensitive while the checkbox is checked
+				gobject_sys::g_object_bind_property(
+					checkbox as *mut _,
+					c"active".as_ptr(),
+					ok_button as *mut _,
+					c"sensitive".as_ptr(),
+					gobject_sys::G_BINDING_SYNC_CREATE,
+				);
+				checkbox
+			}
+			None => ptr::null_mut(),
+		};
+
+		gtk_sys::gtk_widget_show_all(dialog);
+
+		let response = gtk_sys::gtk_dialog_run(dialog as *mut gtk_sys::GtkDialog);
+		let accepted = response == gtk_sys::GTK_RESPONSE_OK
+			&& (checkbox.is_null() || gtk_sys::gtk_toggle_button_get_active(checkbox as *mut gtk_sys::GtkToggleButton) != 0);
+
+		gtk_sys::gtk_widget_destroy(dialog);
+		while gtk_sys::gtk_events_pending() != 0 {
+			gtk_sys::gtk_main_iteration();
+		}
+
+		accepted
+	}
+}
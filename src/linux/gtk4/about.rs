@@ -0,0 +1,33 @@
+use super::*;
+
+pub fn about_dialog(p: &AboutDialog<'_>) {
+	ensure_gtk_initialized();
+
+	let dialog = unsafe { gtk4_sys::gtk_about_dialog_new() };
+	let about = dialog as *mut gtk4_sys::GtkAboutDialog;
+
+	let set = |value: &str, setter: unsafe extern "C" fn(*mut gtk4_sys::GtkAboutDialog, *const c_char)| {
+		if !value.is_empty() {
+			let value = cstring(value);
+			unsafe { setter(about, value.as_ptr()) };
+		}
+	};
+	set(p.name, gtk4_sys::gtk_about_dialog_set_program_name);
+	set(p.version, gtk4_sys::gtk_about_dialog_set_version);
+	set(p.icon, gtk4_sys::gtk_about_dialog_set_logo_icon_name);
+	set(p.comments, gtk4_sys::gtk_about_dialog_set_comments);
+	set(p.license, gtk4_sys::gtk_about_dialog_set_license);
+	set(p.website, gtk4_sys::gtk_about_dialog_set_website);
+
+	if !p.license.is_empty() {
+		unsafe { gtk4_sys::gtk_about_dialog_set_wrap_license(about, 1) };
+	}
+
+	if !p.authors.is_empty() {
+		let author_strings: Vec<CString> = p.authors.iter().map(|author| cstring(author)).collect();
+		let mut author_ptrs: Vec<*const c_char> = author_strings.iter().map(|author| author.as_ptr()).chain([ptr::null()]).collect();
+		unsafe { gtk4_sys::gtk_about_dialog_set_authors(about, author_ptrs.as_mut_ptr()) };
+	}
+
+	run_window(dialog as *mut gtk4_sys::GtkWindow);
+}
@@ -0,0 +1,43 @@
+use super::*;
+
+pub fn app_chooser(p: &AppChooser<'_>) -> Option<String> {
+	ensure_gtk_initialized();
+
+	let dialog = match p.mime_type {
+		Some(mime_type) => {
+			let content_type = cstring(mime_type);
+			unsafe { gtk4_sys::gtk_app_chooser_dialog_new_for_content_type(ptr::null_mut(), gtk4_sys::GTK_DIALOG_MODAL, content_type.as_ptr()) }
+		}
+		None => unsafe {
+			let path = cstring(&p.path.to_string_lossy());
+			let file = gtk4_gio_sys::g_file_new_for_path(path.as_ptr());
+			let dialog = gtk4_sys::gtk_app_chooser_dialog_new(ptr::null_mut(), gtk4_sys::GTK_DIALOG_MODAL, file);
+			g_object_unref(file as *mut _);
+			dialog
+		},
+	};
+
+	let title = cstring(p.title);
+	unsafe {
+		gtk4_sys::gtk_window_set_title(dialog as *mut gtk4_sys::GtkWindow, title.as_ptr());
+	}
+
+	run_dialog_f(dialog as *mut gtk4_sys::GtkDialog, |response| {
+		if response != gtk4_sys::GTK_RESPONSE_OK {
+			return None;
+		}
+
+		unsafe { app_id(gtk4_sys::gtk_app_chooser_get_app_info(dialog as *mut gtk4_sys::GtkAppChooser)) }
+	})
+}
+
+unsafe fn app_id(app_info: *mut gtk4_gio_sys::GAppInfo) -> Option<String> {
+	if app_info.is_null() {
+		return None;
+	}
+
+	let id = gtk4_gio_sys::g_app_info_get_id(app_info);
+	let id = if id.is_null() { None } else { Some(CStr::from_ptr(id).to_string_lossy().into_owned()) };
+	g_object_unref(app_info as *mut _);
+	id
+}
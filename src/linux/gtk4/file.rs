@@ -1,21 +1,74 @@
 use super::*;
 
-fn apply_file_chooser_defaults(dialog: *mut gtk4_sys::GtkFileChooser, p: &FileDialog<'_>, save: bool, multiple: bool) {
+// Returns the added filters in the order of [`utils::dialog_filters`].
+fn apply_file_chooser_defaults(dialog: *mut gtk4_sys::GtkFileChooser, p: &FileDialog<'_>, save: bool, multiple: bool) -> Vec<*mut gtk4_sys::GtkFileFilter> {
+	let mut gtk_filters = Vec::new();
 	unsafe {
 		gtk4_sys::gtk_file_chooser_set_select_multiple(dialog, multiple as i32);
-		let _ = save;
+		// GTK 4 has no show hidden and local only options, remote files without a local path are skipped.
+		if save {
+			gtk4_sys::gtk_file_chooser_set_create_folders(dialog, p.create_folders as i32);
+		}
 
-		if let Some(filters) = p.filters {
-			for filter in filters {
-				add_filter(dialog, filter);
-			}
-			add_filter(dialog, &FileFilter::ALL_FILES);
+		for filter in &utils::dialog_filters(p) {
+			gtk_filters.push(add_filter(dialog, filter));
+		}
+		if let Some(default) = utils::default_filter(p) {
+			gtk4_sys::gtk_file_chooser_set_filter(dialog, gtk_filters[default]);
 		}
 	}
+	for choice in p.choices {
+		add_choice(dialog, choice);
+	}
 
 	if let Some(path) = utils::abspath(p.path) {
 		apply_initial_path(dialog, path.as_ref());
 	}
+	gtk_filters
+}
+
+fn selected_filter(dialog: *mut gtk4_sys::GtkFileChooser, p: &FileDialog<'_>, gtk_filters: &[*mut gtk4_sys::GtkFileFilter]) -> Option<usize> {
+	let selected = unsafe { gtk4_sys::gtk_file_chooser_get_filter(dialog) };
+	if selected.is_null() {
+		return None;
+	}
+	let index = gtk_filters.iter().position(|&gtk_filter| gtk_filter == selected)?;
+	utils::filter_index(p, index)
+}
+
+fn selection(dialog: *mut gtk4_sys::GtkFileChooser, p: &FileDialog<'_>, gtk_filters: &[*mut gtk4_sys::GtkFileFilter]) -> FileSelection {
+	let mut selection = utils::file_selection(p, selected_filter(dialog, p, gtk_filters));
+	for (id, option) in &mut selection.choices {
+		let c_id = cstring(id);
+		let value = unsafe { gtk4_sys::gtk_file_chooser_get_choice(dialog, c_id.as_ptr()) };
+		if !value.is_null() {
+			*option = unsafe { CStr::from_ptr(value) }.to_string_lossy().into_owned();
+		}
+	}
+	selection
+}
+
+fn add_choice(dialog: *mut gtk4_sys::GtkFileChooser, choice: &FileChoice<'_>) {
+	let id = cstring(choice.id);
+	let label = cstring(choice.label);
+	let default = cstring(choice.default);
+
+	// The option ids and labels are null terminated arrays, a choice without options is a checkbox.
+	let option_ids = choice.options.iter().map(|&(id, _)| cstring(id)).collect::<Vec<_>>();
+	let option_labels = choice.options.iter().map(|&(_, label)| cstring(label)).collect::<Vec<_>>();
+	let mut option_id_ptrs = option_ids.iter().map(|id| id.as_ptr()).chain(Some(ptr::null())).collect::<Vec<_>>();
+	let mut option_label_ptrs = option_labels.iter().map(|label| label.as_ptr()).chain(Some(ptr::null())).collect::<Vec<_>>();
+	let (options, labels) = if choice.options.is_empty() {
+		(ptr::null_mut(), ptr::null_mut())
+	}
+	else {
+		(option_id_ptrs.as_mut_ptr(), option_label_ptrs.as_mut_ptr())
+	};
+
+	unsafe {
+		gtk4_sys::gtk_file_chooser_add_choice(dialog, id.as_ptr(), label.as_ptr(), options, labels);
+		gtk4_sys::gtk_file_chooser_set_choice(dialog, id.as_ptr(), default.as_ptr());
+	}
 }
 
 fn apply_initial_path(dialog: *mut gtk4_sys::GtkFileChooser, path: &Path) {
@@ -60,33 +113,52 @@ fn set_file(dialog: *mut gtk4_sys::GtkFileChooser, path: &Path) {
 	}
 }
 
-fn add_filter(dialog: *mut gtk4_sys::GtkFileChooser, filter: &FileFilter) {
+fn add_filter(dialog: *mut gtk4_sys::GtkFileChooser, filter: &FileFilter) -> *mut gtk4_sys::GtkFileFilter {
 	unsafe {
 		let gtk_filter = gtk4_sys::gtk_file_filter_new();
 		let name = cstring(filter.name);
 		gtk4_sys::gtk_file_filter_set_name(gtk_filter, name.as_ptr());
-		for pattern in filter.patterns {
-			let pattern = cstring(pattern);
+		for pattern in utils::glob_patterns(filter.patterns.iter().copied(), utils::GlobSyntax::CaseSensitive) {
+			let pattern = cstring(&pattern);
 			gtk4_sys::gtk_file_filter_add_pattern(gtk_filter, pattern.as_ptr());
 		}
+		for mime_type in filter.mime_types {
+			let mime_type = cstring(mime_type);
+			gtk4_sys::gtk_file_filter_add_mime_type(gtk_filter, mime_type.as_ptr());
+		}
 		gtk4_sys::gtk_file_chooser_add_filter(dialog, gtk_filter);
+		gtk_filter
 	}
 }
 
-pub fn pick_file(p: &FileDialog<'_>) -> Option<PathBuf> {
-	pick_files_impl(p, false).and_then(|files| files.into_iter().next())
+pub fn pick_file(p: &FileDialog<'_>) -> Option<(PathBuf, FileSelection)> {
+	let (files, filter) = pick_files_impl(p, false)?;
+	Some((files.into_iter().next()?, filter))
 }
 
-pub fn pick_files(p: &FileDialog<'_>) -> Option<Vec<PathBuf>> {
+pub fn pick_files(p: &FileDialog<'_>) -> Option<(Vec<PathBuf>, FileSelection)> {
 	pick_files_impl(p, true)
 }
 
-fn pick_files_impl(p: &FileDialog<'_>, multiple: bool) -> Option<Vec<PathBuf>> {
+fn pick_files_impl(p: &FileDialog<'_>, multiple: bool) -> Option<(Vec<PathBuf>, FileSelection)> {
+	run_open_dialog(p, multiple, gfile_to_path_buf)
+}
+
+pub fn pick_uri(p: &FileDialog<'_>) -> Option<String> {
+	let (uris, _) = run_open_dialog(p, false, gfile_to_uri)?;
+	uris.into_iter().next()
+}
+
+pub fn pick_uris(p: &FileDialog<'_>) -> Option<Vec<String>> {
+	run_open_dialog(p, true, gfile_to_uri).map(|(uris, _)| uris)
+}
+
+fn run_open_dialog<T>(p: &FileDialog<'_>, multiple: bool, convert: fn(*mut GFile) -> Option<T>) -> Option<(Vec<T>, FileSelection)> {
 	ensure_gtk_initialized();
 
 	let title = cstring(p.title);
-	let accept = c"Open";
-	let cancel = c"Cancel";
+	let accept = cstring(p.accept_label.unwrap_or("Open"));
+	let cancel = cstring(p.cancel_label.unwrap_or("Cancel"));
 
 	let native = unsafe {
 		gtk4_sys::gtk_file_chooser_native_new(
@@ -99,7 +171,7 @@ fn pick_files_impl(p: &FileDialog<'_>, multiple: bool) -> Option<Vec<PathBuf>> {
 	};
 	let chooser = native as *mut gtk4_sys::GtkFileChooser;
 
-	apply_file_chooser_defaults(chooser, p, false, multiple);
+	let gtk_filters = apply_file_chooser_defaults(chooser, p, false, multiple);
 
 	run_native_dialog_f(native as *mut gtk4_sys::GtkNativeDialog, |response| {
 		if response != gtk4_sys::GTK_RESPONSE_ACCEPT {
@@ -108,22 +180,30 @@ fn pick_files_impl(p: &FileDialog<'_>, multiple: bool) -> Option<Vec<PathBuf>> {
 
 		let result = if multiple {
 			let model = unsafe { gtk4_sys::gtk_file_chooser_get_files(chooser) };
-			collect_file_model(model as *mut GListModel)
+			collect_file_model(model as *mut GListModel, convert)
 		} else {
 			let file = unsafe { gtk4_sys::gtk_file_chooser_get_file(chooser) };
-			gfile_to_path_buf(file as *mut GFile).into_iter().collect()
+			convert(file as *mut GFile).into_iter().collect()
 		};
 
-		Some(result)
+		Some((result, selection(chooser, p, &gtk_filters)))
 	})
 }
 
-pub fn save_file(p: &FileDialog<'_>) -> Option<PathBuf> {
+pub fn save_file(p: &FileDialog<'_>) -> Option<(PathBuf, FileSelection)> {
+	run_save_dialog(p, gfile_to_path_buf)
+}
+
+pub fn save_uri(p: &FileDialog<'_>) -> Option<String> {
+	run_save_dialog(p, gfile_to_uri).map(|(uri, _)| uri)
+}
+
+fn run_save_dialog<T>(p: &FileDialog<'_>, convert: fn(*mut GFile) -> Option<T>) -> Option<(T, FileSelection)> {
 	ensure_gtk_initialized();
 
 	let title = cstring(p.title);
-	let accept = c"Save";
-	let cancel = c"Cancel";
+	let accept = cstring(p.accept_label.unwrap_or("Save"));
+	let cancel = cstring(p.cancel_label.unwrap_or("Cancel"));
 
 	let native = unsafe {
 		gtk4_sys::gtk_file_chooser_native_new(
@@ -136,7 +216,7 @@ pub fn save_file(p: &FileDialog<'_>) -> Option<PathBuf> {
 	};
 	let chooser = native as *mut gtk4_sys::GtkFileChooser;
 
-	apply_file_chooser_defaults(chooser, p, true, false);
+	let gtk_filters = apply_file_chooser_defaults(chooser, p, true, false);
 
 	run_native_dialog_f(native as *mut gtk4_sys::GtkNativeDialog, |response| {
 		if response != gtk4_sys::GTK_RESPONSE_ACCEPT {
@@ -144,6 +224,7 @@ pub fn save_file(p: &FileDialog<'_>) -> Option<PathBuf> {
 		}
 
 		let file = unsafe { gtk4_sys::gtk_file_chooser_get_file(chooser) };
-		gfile_to_path_buf(file as *mut GFile)
+		let value = convert(file as *mut GFile)?;
+		Some((value, selection(chooser, p, &gtk_filters)))
 	})
 }
@@ -12,8 +12,8 @@ fn choose_folders_impl(p: &FileDialog<'_>, multiple: bool) -> Option<Vec<PathBuf
 	ensure_gtk_initialized();
 
 	let title = cstring(p.title);
-	let accept = c"Select";
-	let cancel = c"Cancel";
+	let accept = cstring(p.accept_label.unwrap_or("Select"));
+	let cancel = cstring(p.cancel_label.unwrap_or("Cancel"));
 
 	let native = unsafe {
 		gtk4_sys::gtk_file_chooser_native_new(
@@ -28,6 +28,7 @@ fn choose_folders_impl(p: &FileDialog<'_>, multiple: bool) -> Option<Vec<PathBuf
 
 	unsafe {
 		gtk4_sys::gtk_file_chooser_set_select_multiple(chooser, multiple as i32);
+		gtk4_sys::gtk_file_chooser_set_create_folders(chooser, p.create_folders as i32);
 		if let Some(directory) = p.path {
 			if let Some(c_path) = os_cstring(directory.as_os_str()) {
 				let file = gtk4_gio_sys::g_file_new_for_path(c_path.as_ptr());
@@ -44,7 +45,7 @@ fn choose_folders_impl(p: &FileDialog<'_>, multiple: bool) -> Option<Vec<PathBuf
 
 		let result = if multiple {
 			let model = unsafe { gtk4_sys::gtk_file_chooser_get_files(chooser) };
-			collect_file_model(model as *mut GListModel)
+			collect_file_model(model as *mut GListModel, gfile_to_path_buf)
 		}
 		else {
 			let file = unsafe { gtk4_sys::gtk_file_chooser_get_file(chooser) };
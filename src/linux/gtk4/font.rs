@@ -0,0 +1,47 @@
+use super::*;
+
+pub fn font_picker(p: &FontPicker<'_>) -> Option<FontValue> {
+	ensure_gtk_initialized();
+
+	let title = cstring(p.title);
+	let dialog = unsafe { gtk4_sys::gtk_font_chooser_dialog_new(title.as_ptr(), ptr::null_mut()) };
+
+	if !p.value.is_empty() {
+		let value = cstring(p.value);
+		unsafe {
+			gtk4_sys::gtk_font_chooser_set_font(dialog as *mut gtk4_sys::GtkFontChooser, value.as_ptr());
+		}
+	}
+
+	run_dialog_f(dialog as *mut gtk4_sys::GtkDialog, |response| {
+		if response != gtk4_sys::GTK_RESPONSE_OK {
+			return None;
+		}
+
+		unsafe {
+			let desc = gtk4_sys::gtk_font_chooser_get_font_desc(dialog as *mut gtk4_sys::GtkFontChooser);
+			font_value(desc)
+		}
+	})
+}
+
+unsafe fn font_value(desc: *mut gtk4_pango_sys::PangoFontDescription) -> Option<FontValue> {
+	if desc.is_null() {
+		return None;
+	}
+
+	let family = gtk4_pango_sys::pango_font_description_get_family(desc);
+	let family = if family.is_null() { String::new() } else { CStr::from_ptr(family).to_string_lossy().into_owned() };
+
+	let style = match gtk4_pango_sys::pango_font_description_get_style(desc) {
+		gtk4_pango_sys::PANGO_STYLE_OBLIQUE => FontStyle::Oblique,
+		gtk4_pango_sys::PANGO_STYLE_ITALIC => FontStyle::Italic,
+		_ => FontStyle::Normal,
+	};
+	let weight = gtk4_pango_sys::pango_font_description_get_weight(desc).clamp(100, 1000) as u16;
+	let size = gtk4_pango_sys::pango_font_description_get_size(desc) as f32 / gtk4_pango_sys::PANGO_SCALE as f32;
+
+	gtk4_pango_sys::pango_font_description_free(desc);
+
+	Some(FontValue { family, style, weight, size })
+}
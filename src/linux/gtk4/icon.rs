@@ -0,0 +1,90 @@
+use super::*;
+
+const RESPONSE_BROWSE: i32 = 1;
+
+pub fn icon_picker(p: &IconPicker<'_>) -> Option<IconValue> {
+	ensure_gtk_initialized();
+
+	let names = icons::icon_names(p.context, &settings::icon_theme());
+	let dialog = unsafe { gtk4_sys::gtk_dialog_new() };
+	let title = cstring(p.title);
+
+	let icon_view = unsafe {
+		gtk4_sys::gtk_window_set_title(dialog as *mut gtk4_sys::GtkWindow, title.as_ptr());
+		gtk4_sys::gtk_window_set_default_size(dialog as *mut gtk4_sys::GtkWindow, 560, 480);
+		gtk4_sys::gtk_dialog_add_button(dialog as *mut gtk4_sys::GtkDialog, c"Browse...".as_ptr(), RESPONSE_BROWSE);
+		gtk4_sys::gtk_dialog_add_button(dialog as *mut gtk4_sys::GtkDialog, c"Cancel".as_ptr(), gtk4_sys::GTK_RESPONSE_CANCEL);
+		gtk4_sys::gtk_dialog_add_button(dialog as *mut gtk4_sys::GtkDialog, c"OK".as_ptr(), gtk4_sys::GTK_RESPONSE_OK);
+		gtk4_sys::gtk_dialog_set_default_response(dialog as *mut gtk4_sys::GtkDialog, gtk4_sys::GTK_RESPONSE_OK);
+
+		let content = gtk4_sys::gtk_dialog_get_content_area(dialog as *mut gtk4_sys::GtkDialog);
+		gtk4_sys::gtk_widget_set_margin_start(content as *mut gtk4_sys::GtkWidget, 14);
+		gtk4_sys::gtk_widget_set_margin_end(content as *mut gtk4_sys::GtkWidget, 14);
+		gtk4_sys::gtk_widget_set_margin_top(content as *mut gtk4_sys::GtkWidget, 14);
+		gtk4_sys::gtk_widget_set_margin_bottom(content as *mut gtk4_sys::GtkWidget, 14);
+
+		let scrolled = gtk4_sys::gtk_scrolled_window_new();
+		gtk4_sys::gtk_scrolled_window_set_policy(scrolled as *mut gtk4_sys::GtkScrolledWindow, gtk4_sys::GTK_POLICY_NEVER, gtk4_sys::GTK_POLICY_AUTOMATIC);
+		gtk4_sys::gtk_widget_set_vexpand(scrolled, 1);
+		gtk4_sys::gtk_widget_set_hexpand(scrolled, 1);
+
+		// An icon view only renders the visible icons, themes easily have thousands of them.
+		let store = gtk4_sys::gtk_list_store_newv(1, [gtk4_gobject_sys::G_TYPE_STRING].as_mut_ptr());
+		for name in &names {
+			let name = cstring(name);
+			gtk4_sys::gtk_list_store_insert_with_values(store, ptr::null_mut(), -1, 0, name.as_ptr(), -1);
+		}
+		let icon_view = gtk4_sys::gtk_icon_view_new_with_model(store as *mut gtk4_sys::GtkTreeModel);
+		g_object_unref(store as *mut _);
+		gtk4_sys::gtk_icon_view_set_selection_mode(icon_view as *mut gtk4_sys::GtkIconView, gtk4_sys::GTK_SELECTION_SINGLE);
+		// The icon name is shown as tooltip, labels would make the grid too wide.
+		gtk4_sys::gtk_icon_view_set_tooltip_column(icon_view as *mut gtk4_sys::GtkIconView, 0);
+		let renderer = gtk4_sys::gtk_cell_renderer_pixbuf_new();
+		gtk4_gobject_sys::g_object_set(
+			renderer as *mut gtk4_gobject_sys::GObject,
+			c"icon-size".as_ptr(),
+			gtk4_sys::GTK_ICON_SIZE_LARGE,
+			ptr::null::<c_char>(),
+		);
+		gtk4_sys::gtk_cell_layout_pack_start(icon_view as *mut gtk4_sys::GtkCellLayout, renderer, 1);
+		gtk4_sys::gtk_cell_layout_add_attribute(icon_view as *mut gtk4_sys::GtkCellLayout, renderer, c"icon-name".as_ptr(), 0);
+
+		if let Some(index) = names.iter().position(|name| name == p.value) {
+			let path = gtk4_sys::gtk_tree_path_new_from_indices(index as i32, -1);
+			gtk4_sys::gtk_icon_view_select_path(icon_view as *mut gtk4_sys::GtkIconView, path);
+			gtk4_sys::gtk_icon_view_scroll_to_path(icon_view as *mut gtk4_sys::GtkIconView, path, 1, 0.5, 0.0);
+			gtk4_sys::gtk_tree_path_free(path);
+		}
+
+		gtk4_sys::gtk_scrolled_window_set_child(scrolled as *mut gtk4_sys::GtkScrolledWindow, icon_view);
+		gtk4_sys::gtk_box_append(content as *mut gtk4_sys::GtkBox, scrolled);
+
+		style_response_button(dialog as *mut gtk4_sys::GtkDialog, RESPONSE_BROWSE);
+		style_response_button(dialog as *mut gtk4_sys::GtkDialog, gtk4_sys::GTK_RESPONSE_CANCEL);
+		style_response_button(dialog as *mut gtk4_sys::GtkDialog, gtk4_sys::GTK_RESPONSE_OK);
+		icon_view as *mut gtk4_sys::GtkIconView
+	};
+
+	let (response, selected) = run_dialog_f(dialog as *mut gtk4_sys::GtkDialog, |response| {
+		(response, unsafe { selected_index(icon_view) })
+	});
+
+	match response {
+		gtk4_sys::GTK_RESPONSE_OK => selected.and_then(|index| names.into_iter().nth(index)).map(IconValue::Name),
+		RESPONSE_BROWSE => utils::pick_icon_file(p.title, p.owner),
+		_ => None,
+	}
+}
+
+unsafe fn selected_index(icon_view: *mut gtk4_sys::GtkIconView) -> Option<usize> {
+	let list = gtk4_sys::gtk_icon_view_get_selected_items(icon_view);
+	if list.is_null() {
+		return None;
+	}
+	let path = (*list).data as *mut gtk4_sys::GtkTreePath;
+	let index = *gtk4_sys::gtk_tree_path_get_indices(path);
+	// The selection mode is single, the list holds one path at most.
+	gtk4_sys::gtk_tree_path_free(path);
+	gtk4_glib_sys::g_list_free(list);
+	usize::try_from(index).ok()
+}
@@ -1,18 +1,5 @@
 use super::*;
 
-unsafe fn style_response_button(dialog: *mut gtk4_sys::GtkDialog, response: i32) {
-	let button = gtk4_sys::gtk_dialog_get_widget_for_response(dialog, response);
-	if button.is_null() {
-		return;
-	}
-
-	gtk4_sys::gtk_widget_set_size_request(button, 110, -1);
-	gtk4_sys::gtk_widget_set_margin_start(button, 6);
-	gtk4_sys::gtk_widget_set_margin_end(button, 6);
-	gtk4_sys::gtk_widget_set_margin_top(button, 8);
-	gtk4_sys::gtk_widget_set_margin_bottom(button, 8);
-}
-
 pub fn text_input(p: &TextInput<'_>) -> Option<String> {
 	match p.mode {
 		TextInputMode::SingleLine => text_input_entry(p, false),
@@ -137,3 +124,70 @@ fn text_input_multiline(p: &TextInput<'_>) -> Option<String> {
 		})
 	}
 }
+
+pub fn login_dialog(p: &LoginDialog<'_>) -> Option<Credentials> {
+	ensure_gtk_initialized();
+
+	let dialog = unsafe { gtk4_sys::gtk_dialog_new() };
+	let title = cstring(p.title);
+	let message = cstring(p.message);
+	let username = cstring(p.username);
+	let username_placeholder = c"User name";
+	let password_placeholder = c"Password";
+	let ok = c"OK";
+	let cancel = c"Cancel";
+
+	unsafe {
+		gtk4_sys::gtk_window_set_title(dialog as *mut gtk4_sys::GtkWindow, title.as_ptr());
+		gtk4_sys::gtk_window_set_default_size(dialog as *mut gtk4_sys::GtkWindow, 520, -1);
+		gtk4_sys::gtk_dialog_add_button(dialog as *mut gtk4_sys::GtkDialog, cancel.as_ptr(), gtk4_sys::GTK_RESPONSE_CANCEL);
+		gtk4_sys::gtk_dialog_add_button(dialog as *mut gtk4_sys::GtkDialog, ok.as_ptr(), gtk4_sys::GTK_RESPONSE_OK);
+		gtk4_sys::gtk_dialog_set_default_response(dialog as *mut gtk4_sys::GtkDialog, gtk4_sys::GTK_RESPONSE_OK);
+		style_response_button(dialog as *mut gtk4_sys::GtkDialog, gtk4_sys::GTK_RESPONSE_CANCEL);
+		style_response_button(dialog as *mut gtk4_sys::GtkDialog, gtk4_sys::GTK_RESPONSE_OK);
+
+		let content = gtk4_sys::gtk_dialog_get_content_area(dialog as *mut gtk4_sys::GtkDialog);
+		gtk4_sys::gtk_box_set_spacing(content as *mut gtk4_sys::GtkBox, 10);
+		gtk4_sys::gtk_widget_set_vexpand(content as *mut gtk4_sys::GtkWidget, 1);
+		gtk4_sys::gtk_widget_set_hexpand(content as *mut gtk4_sys::GtkWidget, 1);
+		gtk4_sys::gtk_widget_set_margin_start(content as *mut gtk4_sys::GtkWidget, 14);
+		gtk4_sys::gtk_widget_set_margin_end(content as *mut gtk4_sys::GtkWidget, 14);
+		gtk4_sys::gtk_widget_set_margin_top(content as *mut gtk4_sys::GtkWidget, 14);
+		gtk4_sys::gtk_widget_set_margin_bottom(content as *mut gtk4_sys::GtkWidget, 14);
+
+		let label = gtk4_sys::gtk_label_new(message.as_ptr());
+		gtk4_sys::gtk_label_set_xalign(label as *mut gtk4_sys::GtkLabel, 0.0);
+		let username_entry = gtk4_sys::gtk_entry_new();
+		gtk4_sys::gtk_editable_set_text(username_entry as *mut gtk4_sys::GtkEditable, username.as_ptr());
+		gtk4_sys::gtk_entry_set_placeholder_text(username_entry as *mut gtk4_sys::GtkEntry, username_placeholder.as_ptr());
+		gtk4_sys::gtk_entry_set_activates_default(username_entry as *mut gtk4_sys::GtkEntry, 1);
+		let password_entry = gtk4_sys::gtk_entry_new();
+		gtk4_sys::gtk_entry_set_placeholder_text(password_entry as *mut gtk4_sys::GtkEntry, password_placeholder.as_ptr());
+		gtk4_sys::gtk_entry_set_visibility(password_entry as *mut gtk4_sys::GtkEntry, 0);
+		gtk4_sys::gtk_entry_set_activates_default(password_entry as *mut gtk4_sys::GtkEntry, 1);
+
+		gtk4_sys::gtk_box_append(content as *mut gtk4_sys::GtkBox, label);
+		gtk4_sys::gtk_box_append(content as *mut gtk4_sys::GtkBox, username_entry);
+		gtk4_sys::gtk_box_append(content as *mut gtk4_sys::GtkBox, password_entry);
+		if !p.username.is_empty() {
+			gtk4_sys::gtk_widget_grab_focus(password_entry);
+		}
+
+		run_dialog_f(dialog as *mut gtk4_sys::GtkDialog, |response| {
+			if response == gtk4_sys::GTK_RESPONSE_OK {
+				let username_ptr = gtk4_sys::gtk_editable_get_text(username_entry as *mut gtk4_sys::GtkEditable);
+				let password_ptr = gtk4_sys::gtk_editable_get_text(password_entry as *mut gtk4_sys::GtkEditable);
+				if username_ptr.is_null() || password_ptr.is_null() {
+					None
+				} else {
+					Some(Credentials {
+						username: CStr::from_ptr(username_ptr).to_string_lossy().to_string(),
+						password: CStr::from_ptr(password_ptr).to_string_lossy().to_string(),
+					})
+				}
+			} else {
+				None
+			}
+		})
+	}
+}
@@ -0,0 +1,134 @@
+use std::cell::Cell;
+
+use gtk4_glib_sys::{g_main_context_iteration, g_main_context_pending};
+
+use super::*;
+
+pub struct LogWindowImpl {
+	dialog: *mut gtk4_sys::GtkWidget,
+	text_view: *mut gtk4_sys::GtkWidget,
+	finished: Box<Cell<bool>>,
+}
+
+unsafe extern "C" fn on_close_request(
+	_window: *mut gtk4_sys::GtkWindow,
+	user_data: *mut c_void,
+) -> gboolean {
+	// Returning true stops the window from closing until the job is finished.
+	let finished = &*(user_data as *const Cell<bool>);
+	gboolean::from(!finished.get())
+}
+
+impl LogWindowImpl {
+	pub fn new(title: &str) -> LogWindowImpl {
+		ensure_gtk_initialized();
+
+		let dialog = unsafe { gtk4_sys::gtk_dialog_new() };
+		let finished = Box::new(Cell::new(false));
+		let title = cstring(title);
+		let close = c"Close";
+
+		let text_view = unsafe {
+			g_object_ref_sink(dialog as *mut _);
+			gtk4_sys::gtk_window_set_title(dialog as *mut gtk4_sys::GtkWindow, title.as_ptr());
+			gtk4_sys::gtk_window_set_default_size(dialog as *mut gtk4_sys::GtkWindow, 640, 480);
+			gtk4_sys::gtk_window_set_deletable(dialog as *mut gtk4_sys::GtkWindow, 0);
+			gtk4_sys::gtk_dialog_add_button(dialog as *mut gtk4_sys::GtkDialog, close.as_ptr(), gtk4_sys::GTK_RESPONSE_CLOSE);
+			gtk4_sys::gtk_dialog_set_response_sensitive(dialog as *mut gtk4_sys::GtkDialog, gtk4_sys::GTK_RESPONSE_CLOSE, 0);
+			style_response_button(dialog as *mut gtk4_sys::GtkDialog, gtk4_sys::GTK_RESPONSE_CLOSE);
+
+			let callback: gtk4_gobject_sys::GCallback = std::mem::transmute(Some(
+				on_close_request
+					as unsafe extern "C" fn(*mut gtk4_sys::GtkWindow, *mut c_void) -> gboolean,
+			));
+			g_signal_connect_data(
+				dialog as *mut _,
+				c"close-request".as_ptr(),
+				callback,
+				&*finished as *const Cell<bool> as *mut _,
+				None,
+				0,
+			);
+
+			let content = gtk4_sys::gtk_dialog_get_content_area(dialog as *mut gtk4_sys::GtkDialog);
+			gtk4_sys::gtk_widget_set_margin_start(content as *mut gtk4_sys::GtkWidget, 14);
+			gtk4_sys::gtk_widget_set_margin_end(content as *mut gtk4_sys::GtkWidget, 14);
+			gtk4_sys::gtk_widget_set_margin_top(content as *mut gtk4_sys::GtkWidget, 14);
+			gtk4_sys::gtk_widget_set_margin_bottom(content as *mut gtk4_sys::GtkWidget, 14);
+
+			let scrolled = gtk4_sys::gtk_scrolled_window_new();
+			let text_view = gtk4_sys::gtk_text_view_new();
+			gtk4_sys::gtk_text_view_set_editable(text_view as *mut gtk4_sys::GtkTextView, 0);
+			gtk4_sys::gtk_text_view_set_cursor_visible(text_view as *mut gtk4_sys::GtkTextView, 0);
+			gtk4_sys::gtk_text_view_set_monospace(text_view as *mut gtk4_sys::GtkTextView, 1);
+
+			gtk4_sys::gtk_widget_set_size_request(scrolled, 480, 280);
+			gtk4_sys::gtk_widget_set_vexpand(scrolled, 1);
+			gtk4_sys::gtk_widget_set_hexpand(scrolled, 1);
+			gtk4_sys::gtk_scrolled_window_set_child(scrolled as *mut gtk4_sys::GtkScrolledWindow, text_view);
+			gtk4_sys::gtk_box_append(content as *mut gtk4_sys::GtkBox, scrolled);
+
+			gtk4_sys::gtk_window_present(dialog as *mut gtk4_sys::GtkWindow);
+			text_view
+		};
+
+		pump_events();
+		LogWindowImpl { dialog, text_view, finished }
+	}
+
+	pub fn append_line(&mut self, line: &str) {
+		let line = cstring(line);
+		unsafe {
+			let text_view = self.text_view as *mut gtk4_sys::GtkTextView;
+			let buffer = gtk4_sys::gtk_text_view_get_buffer(text_view);
+			let mut end = std::mem::zeroed();
+			gtk4_sys::gtk_text_buffer_get_end_iter(buffer, &mut end);
+			gtk4_sys::gtk_text_buffer_insert(buffer, &mut end, line.as_ptr(), -1);
+			gtk4_sys::gtk_text_buffer_insert(buffer, &mut end, c"\n".as_ptr(), -1);
+			gtk4_sys::gtk_text_buffer_place_cursor(buffer, &end);
+			gtk4_sys::gtk_text_view_scroll_to_mark(text_view, gtk4_sys::gtk_text_buffer_get_insert(buffer), 0.0, 0, 0.0, 1.0);
+		}
+		pump_events();
+	}
+
+	pub fn set_title(&mut self, title: &str) {
+		let title = cstring(title);
+		unsafe {
+			gtk4_sys::gtk_window_set_title(self.dialog as *mut gtk4_sys::GtkWindow, title.as_ptr());
+		}
+		pump_events();
+	}
+
+	pub fn finish(&mut self) {
+		self.finished.set(true);
+		unsafe {
+			gtk4_sys::gtk_dialog_set_response_sensitive(self.dialog as *mut gtk4_sys::GtkDialog, gtk4_sys::GTK_RESPONSE_CLOSE, 1);
+			gtk4_sys::gtk_window_set_deletable(self.dialog as *mut gtk4_sys::GtkWindow, 1);
+		}
+		pump_events();
+	}
+
+	pub fn wait_closed(self) {
+		run_dialog(self.dialog as *mut gtk4_sys::GtkDialog);
+	}
+}
+
+impl Drop for LogWindowImpl {
+	fn drop(&mut self) {
+		unsafe {
+			gtk4_sys::gtk_window_destroy(self.dialog as *mut gtk4_sys::GtkWindow);
+			g_object_unref(self.dialog as *mut _);
+		}
+		pump_events();
+	}
+}
+
+// There is no main loop running while the caller works, events are only handled here.
+// The window stays unresponsive between calls, which the LogWindow docs point out.
+fn pump_events() {
+	unsafe {
+		while g_main_context_pending(ptr::null_mut()) != 0 {
+			g_main_context_iteration(ptr::null_mut(), 0);
+		}
+	}
+}
@@ -2,36 +2,58 @@ use std::ffi::{CStr, CString, OsStr};
 use std::os::raw::{c_char, c_void};
 use std::{ptr, sync};
 
-use gtk4_gio_sys::{g_file_get_path, g_list_model_get_item, g_list_model_get_n_items, GFile, GListModel};
-use gtk4_glib_sys::{g_free, g_main_loop_new, g_main_loop_quit, g_main_loop_run, g_main_loop_unref, GMainLoop};
+use gtk4_gio_sys::{g_file_get_path, g_file_get_uri, g_list_model_get_item, g_list_model_get_n_items, GFile, GListModel};
+use gtk4_glib_sys::{g_free, g_main_loop_new, g_main_loop_quit, g_main_loop_run, g_main_loop_unref, gboolean, GMainLoop, GTRUE};
 use gtk4_gobject_sys::{
 	g_object_ref_sink, g_object_unref, g_signal_connect_data, g_signal_handler_disconnect,
 };
 
 use super::*;
 
+mod about;
+mod app;
 mod color;
 mod file;
 mod folder;
+mod font;
+mod icon;
 mod input;
+mod log;
 mod message;
+mod print;
+mod settings;
+mod viewer;
+
+pub use self::log::LogWindowImpl;
 
 pub fn message_box(p: &MessageBox<'_>) -> Option<MessageResult> {
 	message::show(p)
 }
 
-pub fn pick_file(p: &FileDialog<'_>) -> Option<PathBuf> {
+pub fn pick_file(p: &FileDialog<'_>) -> Option<(PathBuf, FileSelection)> {
 	file::pick_file(p)
 }
 
-pub fn pick_files(p: &FileDialog<'_>) -> Option<Vec<PathBuf>> {
+pub fn pick_files(p: &FileDialog<'_>) -> Option<(Vec<PathBuf>, FileSelection)> {
 	file::pick_files(p)
 }
 
-pub fn save_file(p: &FileDialog<'_>) -> Option<PathBuf> {
+pub fn save_file(p: &FileDialog<'_>) -> Option<(PathBuf, FileSelection)> {
 	file::save_file(p)
 }
 
+pub fn pick_uri(p: &FileDialog<'_>) -> Option<String> {
+	file::pick_uri(p)
+}
+
+pub fn pick_uris(p: &FileDialog<'_>) -> Option<Vec<String>> {
+	file::pick_uris(p)
+}
+
+pub fn save_uri(p: &FileDialog<'_>) -> Option<String> {
+	file::save_uri(p)
+}
+
 pub fn choose_folder(p: &FileDialog<'_>) -> Option<PathBuf> {
 	folder::choose_folder(p)
 }
@@ -44,10 +66,42 @@ pub fn text_input(p: &TextInput<'_>) -> Option<String> {
 	input::text_input(p)
 }
 
+pub fn login_dialog(p: &LoginDialog<'_>) -> Option<Credentials> {
+	input::login_dialog(p)
+}
+
+pub fn text_viewer(p: &TextViewer<'_>) -> bool {
+	viewer::text_viewer(p)
+}
+
+pub fn about_dialog(p: &AboutDialog<'_>) {
+	about::about_dialog(p)
+}
+
 pub fn color_picker(p: &ColorPicker<'_>) -> Option<ColorValue> {
 	color::color_picker(p)
 }
 
+pub fn font_picker(p: &FontPicker<'_>) -> Option<FontValue> {
+	font::font_picker(p)
+}
+
+pub fn print_dialog(p: &PrintDialog<'_>) -> Option<PrintSettings> {
+	print::print_dialog(p)
+}
+
+pub fn app_chooser(p: &AppChooser<'_>) -> Option<String> {
+	app::app_chooser(p)
+}
+
+pub fn icon_picker(p: &IconPicker<'_>) -> Option<IconValue> {
+	icon::icon_picker(p)
+}
+
+pub fn appearance() -> Appearance {
+	settings::appearance()
+}
+
 static GTK_INITIALIZED: sync::OnceLock<bool> = sync::OnceLock::new();
 
 fn ensure_gtk_initialized() {
@@ -55,6 +109,7 @@ fn ensure_gtk_initialized() {
 	if !ok {
 		panic!("Failed to initialize GTK4 backend. Ensure a graphical session is available.");
 	}
+	settings::apply_color_scheme();
 }
 
 fn cstring(value: &str) -> CString {
@@ -83,7 +138,22 @@ fn gfile_to_path_buf(file: *mut GFile) -> Option<PathBuf> {
 	c_to_path_buf(path_ptr)
 }
 
-fn collect_file_model(model: *mut GListModel) -> Vec<PathBuf> {
+fn gfile_to_uri(file: *mut GFile) -> Option<String> {
+	if file.is_null() {
+		return None;
+	}
+	let uri_ptr = unsafe { g_file_get_uri(file) };
+	unsafe { g_object_unref(file as *mut _) };
+	if uri_ptr.is_null() {
+		return None;
+	}
+	let uri = unsafe { CStr::from_ptr(uri_ptr).to_string_lossy().into_owned() };
+	unsafe { g_free(uri_ptr as *mut _) };
+	Some(uri)
+}
+
+// Takes ownership of the model and its files.
+fn collect_file_model<T>(model: *mut GListModel, convert: fn(*mut GFile) -> Option<T>) -> Vec<T> {
 	if model.is_null() {
 		return Vec::new();
 	}
@@ -92,16 +162,26 @@ fn collect_file_model(model: *mut GListModel) -> Vec<PathBuf> {
 	let count = unsafe { g_list_model_get_n_items(model) };
 	for index in 0..count {
 		let item = unsafe { g_list_model_get_item(model, index) };
-		let file = item as *mut GFile;
-		if let Some(path) = gfile_to_path_buf(file) {
-			result.push(path);
-		}
+		result.extend(convert(item as *mut GFile));
 	}
 
 	unsafe { g_object_unref(model as *mut _) };
 	result
 }
 
+unsafe fn style_response_button(dialog: *mut gtk4_sys::GtkDialog, response: i32) {
+	let button = gtk4_sys::gtk_dialog_get_widget_for_response(dialog, response);
+	if button.is_null() {
+		return;
+	}
+
+	gtk4_sys::gtk_widget_set_size_request(button, 110, -1);
+	gtk4_sys::gtk_widget_set_margin_start(button, 6);
+	gtk4_sys::gtk_widget_set_margin_end(button, 6);
+	gtk4_sys::gtk_widget_set_margin_top(button, 8);
+	gtk4_sys::gtk_widget_set_margin_bottom(button, 8);
+}
+
 #[repr(C)]
 struct ResponseState {
 	loop_: *mut GMainLoop,
@@ -212,3 +292,40 @@ fn run_native_dialog_f<T, F: FnOnce(i32) -> T>(dialog: *mut gtk4_sys::GtkNativeD
 	}
 	result
 }
+
+unsafe extern "C" fn on_window_close_request(
+	_window: *mut gtk4_sys::GtkWindow,
+	user_data: *mut c_void,
+) -> gboolean {
+	g_main_loop_quit(user_data as *mut GMainLoop);
+	GTRUE
+}
+
+/// Present a window without response signal and block until the user closes it, then destroy it.
+fn run_window(window: *mut gtk4_sys::GtkWindow) {
+	let loop_ = unsafe { g_main_loop_new(ptr::null_mut(), 0) };
+
+	unsafe {
+		g_object_ref_sink(window as *mut _);
+		let callback: gtk4_gobject_sys::GCallback = std::mem::transmute(Some(
+			on_window_close_request
+				as unsafe extern "C" fn(*mut gtk4_sys::GtkWindow, *mut c_void) -> gboolean,
+		));
+		let handler_id = g_signal_connect_data(
+			window as *mut _,
+			c"close-request".as_ptr(),
+			callback,
+			loop_ as *mut _,
+			None,
+			0,
+		);
+
+		gtk4_sys::gtk_window_present(window);
+		g_main_loop_run(loop_);
+		g_signal_handler_disconnect(window as *mut _, handler_id);
+		g_main_loop_unref(loop_);
+
+		gtk4_sys::gtk_window_destroy(window);
+		g_object_unref(window as *mut _);
+	}
+}
@@ -0,0 +1,77 @@
+use std::slice;
+
+use super::*;
+
+pub fn print_dialog(p: &PrintDialog<'_>) -> Option<PrintSettings> {
+	ensure_gtk_initialized();
+
+	let title = cstring(p.title);
+	let dialog = unsafe { gtk4_sys::gtk_print_unix_dialog_new(title.as_ptr(), ptr::null_mut()) } as *mut gtk4_sys::GtkPrintUnixDialog;
+	unsafe { gtk4_sys::gtk_print_unix_dialog_set_embed_page_setup(dialog, GTRUE) };
+
+	run_dialog_f(dialog as *mut gtk4_sys::GtkDialog, |response| {
+		if response != gtk4_sys::GTK_RESPONSE_OK {
+			return None;
+		}
+
+		unsafe {
+			print_settings(gtk4_sys::gtk_print_unix_dialog_get_settings(dialog), gtk4_sys::gtk_print_unix_dialog_get_page_setup(dialog))
+		}
+	})
+}
+
+unsafe fn print_settings(settings: *mut gtk4_sys::GtkPrintSettings, page_setup: *mut gtk4_sys::GtkPageSetup) -> Option<PrintSettings> {
+	if settings.is_null() {
+		return None;
+	}
+
+	let printer = gtk4_sys::gtk_print_settings_get_printer(settings);
+	let printer = if printer.is_null() { String::new() } else { CStr::from_ptr(printer).to_string_lossy().into_owned() };
+	let copies = gtk4_sys::gtk_print_settings_get_n_copies(settings).max(1) as u32;
+
+	let mut page_ranges = Vec::new();
+	if gtk4_sys::gtk_print_settings_get_print_pages(settings) == gtk4_sys::GTK_PRINT_PAGES_RANGES {
+		let mut count = 0;
+		let ranges = gtk4_sys::gtk_print_settings_get_page_ranges(settings, &mut count);
+		if !ranges.is_null() {
+			for range in slice::from_raw_parts(ranges, count.max(0) as usize) {
+				let first = range.start.max(0) as u32;
+				page_ranges.push((first + 1, (range.end.max(0) as u32).max(first) + 1));
+			}
+			g_free(ranges as *mut _);
+		}
+	}
+
+	g_object_unref(settings as *mut _);
+
+	let mut result = PrintSettings {
+		printer,
+		copies,
+		page_ranges,
+		paper: String::new(),
+		paper_width: 0.0,
+		paper_height: 0.0,
+		orientation: PageOrientation::Portrait,
+		token: None,
+	};
+
+	if !page_setup.is_null() {
+		let size = gtk4_sys::gtk_page_setup_get_paper_size(page_setup);
+		if !size.is_null() {
+			let name = gtk4_sys::gtk_paper_size_get_name(size);
+			if !name.is_null() {
+				result.paper = CStr::from_ptr(name).to_string_lossy().into_owned();
+			}
+			result.paper_width = gtk4_sys::gtk_paper_size_get_width(size, gtk4_sys::GTK_UNIT_MM);
+			result.paper_height = gtk4_sys::gtk_paper_size_get_height(size, gtk4_sys::GTK_UNIT_MM);
+		}
+		result.orientation = match gtk4_sys::gtk_page_setup_get_orientation(page_setup) {
+			gtk4_sys::GTK_PAGE_ORIENTATION_LANDSCAPE => PageOrientation::Landscape,
+			gtk4_sys::GTK_PAGE_ORIENTATION_REVERSE_PORTRAIT => PageOrientation::ReversePortrait,
+			gtk4_sys::GTK_PAGE_ORIENTATION_REVERSE_LANDSCAPE => PageOrientation::ReverseLandscape,
+			_ => PageOrientation::Portrait,
+		};
+	}
+
+	Some(result)
+}
@@ -0,0 +1,110 @@
+use super::*;
+
+// The settings as configured by the desktop, before any color scheme was forced.
+struct DesktopSettings {
+	prefer_dark: bool,
+	theme: String,
+}
+
+static DESKTOP_SETTINGS: sync::OnceLock<DesktopSettings> = sync::OnceLock::new();
+
+fn desktop_settings(settings: *mut gtk4_sys::GtkSettings) -> &'static DesktopSettings {
+	DESKTOP_SETTINGS.get_or_init(|| {
+		let mut prefer_dark: gtk4_glib_sys::gboolean = 0;
+		let mut theme_name: *mut c_char = ptr::null_mut();
+		unsafe {
+			gtk4_gobject_sys::g_object_get(
+				settings as *mut gtk4_gobject_sys::GObject,
+				c"gtk-application-prefer-dark-theme".as_ptr(),
+				&mut prefer_dark,
+				c"gtk-theme-name".as_ptr(),
+				&mut theme_name,
+				ptr::null::<c_char>(),
+			);
+		}
+
+		let theme = if theme_name.is_null() {
+			String::new()
+		}
+		else {
+			let theme = unsafe { CStr::from_ptr(theme_name).to_string_lossy().into_owned() };
+			unsafe { g_free(theme_name as *mut _) };
+			theme
+		};
+		DesktopSettings { prefer_dark: prefer_dark != 0, theme }
+	})
+}
+
+pub fn appearance() -> Appearance {
+	ensure_gtk_initialized();
+
+	let settings = unsafe { gtk4_sys::gtk_settings_get_default() };
+	if settings.is_null() {
+		return Appearance::default();
+	}
+	let desktop = desktop_settings(settings);
+
+	let color_scheme = if desktop.prefer_dark { ColorScheme::Dark } else { gtk_theme_color_scheme(&desktop.theme) };
+	// HighContrast is the theme GTK switches to for high contrast.
+	let high_contrast = desktop.theme.starts_with("HighContrast");
+	Appearance { color_scheme, accent_color: None, high_contrast }
+}
+
+// Called before every dialog since the color scheme can be changed at any time.
+pub fn apply_color_scheme() {
+	let settings = unsafe { gtk4_sys::gtk_settings_get_default() };
+	if settings.is_null() {
+		return;
+	}
+	let desktop = desktop_settings(settings);
+
+	let prefer_dark = match utils::color_scheme() {
+		ColorScheme::NoPreference => desktop.prefer_dark,
+		ColorScheme::Light => false,
+		ColorScheme::Dark => true,
+	};
+	unsafe {
+		gtk4_gobject_sys::g_object_set(
+			settings as *mut gtk4_gobject_sys::GObject,
+			c"gtk-application-prefer-dark-theme".as_ptr(),
+			prefer_dark as gtk4_glib_sys::gboolean,
+			ptr::null::<c_char>(),
+		);
+	}
+
+	// A dark theme like Adwaita-dark ignores the preference, switch to its light variant instead.
+	if let Some(light_theme) = desktop.theme.strip_suffix("-dark") {
+		let theme = if utils::color_scheme() == ColorScheme::Light { light_theme } else { &desktop.theme };
+		let theme = cstring(theme);
+		unsafe {
+			gtk4_gobject_sys::g_object_set(
+				settings as *mut gtk4_gobject_sys::GObject,
+				c"gtk-theme-name".as_ptr(),
+				theme.as_ptr(),
+				ptr::null::<c_char>(),
+			);
+		}
+	}
+}
+
+// The icon theme GTK looks icons up in, which may differ from the desktop one when set in `settings.ini`.
+pub fn icon_theme() -> String {
+	let settings = unsafe { gtk4_sys::gtk_settings_get_default() };
+	let mut theme_name: *mut c_char = ptr::null_mut();
+	if !settings.is_null() {
+		unsafe {
+			gtk4_gobject_sys::g_object_get(
+				settings as *mut gtk4_gobject_sys::GObject,
+				c"gtk-icon-theme-name".as_ptr(),
+				&mut theme_name,
+				ptr::null::<c_char>(),
+			);
+		}
+	}
+	if theme_name.is_null() {
+		return icons::desktop_icon_theme();
+	}
+	let theme = unsafe { CStr::from_ptr(theme_name).to_string_lossy().into_owned() };
+	unsafe { g_free(theme_name as *mut _) };
+	theme
+}
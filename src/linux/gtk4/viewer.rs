@@ -0,0 +1,69 @@
+use super::*;
+
+pub fn text_viewer(p: &TextViewer<'_>) -> bool {
+	let Some(text) = p.content.load() else {
+		return false;
+	};
+
+	ensure_gtk_initialized();
+
+	let dialog = unsafe { gtk4_sys::gtk_dialog_new() };
+	let title = cstring(p.title);
+	let text = cstring(&text);
+	let ok = c"OK";
+	let cancel = c"Cancel";
+
+	unsafe {
+		gtk4_sys::gtk_window_set_title(dialog as *mut gtk4_sys::GtkWindow, title.as_ptr());
+		gtk4_sys::gtk_window_set_default_size(dialog as *mut gtk4_sys::GtkWindow, 640, 480);
+		gtk4_sys::gtk_dialog_add_button(dialog as *mut gtk4_sys::GtkDialog, cancel.as_ptr(), gtk4_sys::GTK_RESPONSE_CANCEL);
+		let ok_button = gtk4_sys::gtk_dialog_add_button(dialog as *mut gtk4_sys::GtkDialog, ok.as_ptr(), gtk4_sys::GTK_RESPONSE_OK);
+
+		let content = gtk4_sys::gtk_dialog_get_content_area(dialog as *mut gtk4_sys::GtkDialog);
+		gtk4_sys::gtk_box_set_spacing(content as *mut gtk4_sys::GtkBox, 10);
+		gtk4_sys::gtk_widget_set_margin_start(content as *mut gtk4_sys::GtkWidget, 14);
+		gtk4_sys::gtk_widget_set_margin_end(content as *mut gtk4_sys::GtkWidget, 14);
+		gtk4_sys::gtk_widget_set_margin_top(content as *mut gtk4_sys::GtkWidget, 14);
+		gtk4_sys::gtk_widget_set_margin_bottom(content as *mut gtk4_sys::GtkWidget, 14);
+
+		let scrolled = gtk4_sys::gtk_scrolled_window_new();
+		let text_view = gtk4_sys::gtk_text_view_new();
+		gtk4_sys::gtk_text_view_set_editable(text_view as *mut gtk4_sys::GtkTextView, 0);
+		gtk4_sys::gtk_text_view_set_cursor_visible(text_view as *mut gtk4_sys::GtkTextView, 0);
+		gtk4_sys::gtk_text_view_set_wrap_mode(text_view as *mut gtk4_sys::GtkTextView, gtk4_sys::GTK_WRAP_WORD_CHAR);
+		let buffer = gtk4_sys::gtk_text_view_get_buffer(text_view as *mut gtk4_sys::GtkTextView);
+		gtk4_sys::gtk_text_buffer_set_text(buffer, text.as_ptr(), -1);
+
+		gtk4_sys::gtk_widget_set_size_request(scrolled, 480, 280);
+		gtk4_sys::gtk_widget_set_vexpand(scrolled, 1);
+		gtk4_sys::gtk_widget_set_hexpand(scrolled, 1);
+		gtk4_sys::gtk_scrolled_window_set_child(scrolled as *mut gtk4_sys::GtkScrolledWindow, text_view);
+		gtk4_sys::gtk_box_append(content as *mut gtk4_sys::GtkBox, scrolled);
+
+		let checkbox = match p.checkbox {
+			Some(label) => {
+				let label = cstring(label);
+				let checkbox = gtk4_sys::gtk_check_button_new_with_label(label.as_ptr());
+				gtk4_sys::gtk_box_append(content as *mut gtk4_sys::GtkBox, checkbox);
+				// The OK button is only sensitive while the checkbox is checked
+				gtk4_gobject_sys::g_object_bind_property(
+					checkbox as *mut _,
+					c"active".as_ptr(),
+					ok_button as *mut _,
+					c"sensitive".as_ptr(),
+					gtk4_gobject_sys::G_BINDING_SYNC_CREATE,
+				);
+				checkbox
+			}
+			None => ptr::null_mut(),
+		};
+
+		style_response_button(dialog as *mut gtk4_sys::GtkDialog, gtk4_sys::GTK_RESPONSE_CANCEL);
+		style_response_button(dialog as *mut gtk4_sys::GtkDialog, gtk4_sys::GTK_RESPONSE_OK);
+
+		run_dialog_f(dialog as *mut gtk4_sys::GtkDialog, |response| {
+			response == gtk4_sys::GTK_RESPONSE_OK
+				&& (checkbox.is_null() || gtk4_sys::gtk_check_button_get_active(checkbox as *mut gtk4_sys::GtkCheckButton) != 0)
+		})
+	}
+}
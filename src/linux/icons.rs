@@ -0,0 +1,118 @@
+use std::collections::BTreeSet;
+use std::fs;
+
+use super::*;
+
+// Lists the icon names of a context in the icon theme, the themes it inherits from and hicolor, sorted and without duplicates.
+pub fn icon_names(context: IconContext, theme: &str) -> Vec<String> {
+	let context_dir = match context {
+		IconContext::Any => None,
+		IconContext::Action => Some("actions"),
+		IconContext::Application => Some("apps"),
+		IconContext::Category => Some("categories"),
+		IconContext::Device => Some("devices"),
+		IconContext::Emblem => Some("emblems"),
+		IconContext::Emote => Some("emotes"),
+		IconContext::MimeType => Some("mimetypes"),
+		IconContext::Place => Some("places"),
+		IconContext::Status => Some("status"),
+	};
+
+	let mut icon_dirs = xdg_data_dirs().into_iter().map(|dir| dir.join("icons")).collect::<Vec<_>>();
+	if let Some(home) = env::var_os("HOME") {
+		icon_dirs.insert(1, PathBuf::from(home).join(".icons"));
+	}
+
+	let mut names = BTreeSet::new();
+	for theme in theme_chain(&icon_dirs, theme) {
+		for dir in &icon_dirs {
+			// Theme directories are laid out as `<size>/<context>` or `<context>/<size>`.
+			collect_icons(&dir.join(&theme), context_dir, false, 0, &mut names);
+		}
+	}
+	names.into_iter().collect()
+}
+
+// The icon theme configured by the desktop, for backends that cannot ask the toolkit.
+pub fn desktop_icon_theme() -> String {
+	let desktop = env::var("XDG_CURRENT_DESKTOP").unwrap_or_default();
+	let theme = if desktop.split(':').any(|name| name.eq_ignore_ascii_case("KDE")) {
+		kde_icon_theme()
+	}
+	else {
+		gnome_icon_theme()
+	};
+	theme.filter(|theme| !theme.is_empty()).unwrap_or_else(|| String::from("hicolor"))
+}
+
+fn gnome_icon_theme() -> Option<String> {
+	let output = process::Command::new("gsettings").args(["get", "org.gnome.desktop.interface", "icon-theme"]).output().ok()?;
+	let value = String::from_utf8(output.stdout).ok()?;
+	output.status.success().then(|| value.trim().trim_matches('\'').to_string())
+}
+
+fn kde_icon_theme() -> Option<String> {
+	let config = env::var_os("XDG_CONFIG_HOME").map(PathBuf::from)
+		.or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+	let kdeglobals = fs::read_to_string(config.join("kdeglobals")).ok();
+	// Plasma uses Breeze when the user never changed the icon theme.
+	Some(index_value(kdeglobals.as_deref().unwrap_or_default(), "Icons", "Theme").unwrap_or("breeze").to_string())
+}
+
+// The theme followed by the themes it inherits from, in lookup order, always ending with hicolor.
+fn theme_chain(icon_dirs: &[PathBuf], theme: &str) -> Vec<String> {
+	let mut chain = Vec::new();
+	let mut pending = vec![theme.to_string()];
+	while let Some(theme) = pending.pop() {
+		if theme.is_empty() || theme.contains('/') || chain.contains(&theme) {
+			continue;
+		}
+		let index = icon_dirs.iter().find_map(|dir| fs::read_to_string(dir.join(&theme).join("index.theme")).ok());
+		if let Some(inherits) = index.as_deref().and_then(|index| index_value(index, "Icon Theme", "Inherits")) {
+			pending.extend(inherits.rsplit(',').map(|name| name.trim().to_string()));
+		}
+		chain.push(theme);
+	}
+	if !chain.iter().any(|theme| theme == "hicolor") {
+		chain.push(String::from("hicolor"));
+	}
+	chain
+}
+
+// Reads a key from a group of an ini style file like `index.theme` or `kdeglobals`.
+fn index_value<'a>(text: &'a str, group: &str, key: &str) -> Option<&'a str> {
+	let mut in_group = false;
+	for line in text.lines().map(str::trim) {
+		if let Some(name) = line.strip_prefix('[').and_then(|line| line.strip_suffix(']')) {
+			in_group = name == group;
+		}
+		else if let Some((name, value)) = line.split_once('=').filter(|_| in_group) {
+			if name.trim() == key {
+				return Some(value.trim());
+			}
+		}
+	}
+	None
+}
+
+fn collect_icons(dir: &Path, context_dir: Option<&str>, in_context: bool, depth: u32, names: &mut BTreeSet<String>) {
+	if depth > 3 {
+		return;
+	}
+	let Ok(read_dir) = fs::read_dir(dir) else { return };
+	for item in read_dir.flatten() {
+		let path = item.path();
+		if path.is_dir() {
+			let in_context = in_context || context_dir.is_none() || context_dir == item.file_name().to_str();
+			collect_icons(&path, context_dir, in_context, depth + 1, names);
+			continue;
+		}
+		if !in_context {
+			continue;
+		}
+		let is_image = matches!(path.extension().and_then(OsStr::to_str), Some("png" | "svg" | "svgz" | "xpm"));
+		if let Some(name) = path.file_stem().and_then(OsStr::to_str).filter(|_| is_image) {
+			names.insert(name.to_string());
+		}
+	}
+}
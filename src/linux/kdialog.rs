@@ -44,22 +44,46 @@ pub fn message_box(p: &MessageBox<'_>) -> Option<MessageResult> {
 
 
 
-pub fn pick_file(p: &FileDialog<'_>) -> Option<PathBuf> {
-	pick_files_impl(p, false).and_then(|paths| paths.into_iter().next())
+pub fn pick_file(p: &FileDialog<'_>) -> Option<(PathBuf, FileSelection)> {
+	let path = pick_files_impl(p, false)?.into_iter().next()?;
+	// KDialog does not report the selected filter, guess it from the file name.
+	let filter = utils::guess_filter(p, &path, filter_patterns);
+	Some((path, utils::file_selection(p, filter)))
 }
 
-pub fn pick_files(p: &FileDialog<'_>) -> Option<Vec<PathBuf>> {
-	pick_files_impl(p, true)
+pub fn pick_files(p: &FileDialog<'_>) -> Option<(Vec<PathBuf>, FileSelection)> {
+	let paths = pick_files_impl(p, true)?;
+	let filter = paths.first().and_then(|path| utils::guess_filter(p, path, filter_patterns));
+	Some((paths, utils::file_selection(p, filter)))
 }
 
 fn pick_files_impl(p: &FileDialog<'_>, multiple: bool) -> Option<Vec<PathBuf>> {
-	let filters = filters_string(p.filters);
+	let lines = open_dialog_lines(p, "--getopenfilename", multiple)?;
+	Some(lines.into_iter().map(|line| PathBuf::from(OsStr::from_bytes(&line))).collect())
+}
+
+pub fn pick_uri(p: &FileDialog<'_>) -> Option<String> {
+	pick_uris_impl(p, false)?.into_iter().next()
+}
+
+pub fn pick_uris(p: &FileDialog<'_>) -> Option<Vec<String>> {
+	pick_uris_impl(p, true)
+}
+
+fn pick_uris_impl(p: &FileDialog<'_>, multiple: bool) -> Option<Vec<String>> {
+	let lines = open_dialog_lines(p, "--getopenurl", multiple)?;
+	Some(lines.into_iter().map(|line| String::from_utf8_lossy(&line).into_owned()).collect())
+}
+
+// Returns the non-empty output lines of the open dialog, one per selected file.
+fn open_dialog_lines(p: &FileDialog<'_>, kind: &str, multiple: bool) -> Option<Vec<Vec<u8>>> {
+	let filters = filters_string(p);
 	let file_path = utils::abspath(p.path);
 	let file_path = file_path.as_deref().map(Path::as_os_str).unwrap_or(os("."));
 
 	let args = [
 		os("--title"), os(p.title),
-		os("--getopenfilename"), file_path, os(&filters),
+		os(kind), file_path, os(&filters),
 		os("--multiple"),
 		os("--separate-output"),
 	];
@@ -73,18 +97,43 @@ fn pick_files_impl(p: &FileDialog<'_>, multiple: bool) -> Option<Vec<PathBuf>> {
 
 	Some(output.split(|&byte| byte == b'\n')
 		.filter(|line| !line.is_empty())
-		.map(|line| PathBuf::from(OsStr::from_bytes(line)))
+		.map(|line| line.to_vec())
 		.collect::<Vec<_>>())
 }
 
-pub fn save_file(p: &FileDialog<'_>) -> Option<PathBuf> {
-	let filters = filters_string(p.filters);
+pub fn save_file(p: &FileDialog<'_>) -> Option<(PathBuf, FileSelection)> {
+	// The save dialog does not ask before replacing a file, ask with a follow-up question instead.
+	let mut retry: Option<PathBuf> = None;
+	loop {
+		let dialog = FileDialog { path: retry.as_deref().or(p.path), ..*p };
+		let (path, selection) = save_file_once(&dialog)?;
+		if !p.confirm_overwrite || !path.exists() || utils::confirm_overwrite(p, &path) {
+			return Some((path, selection));
+		}
+		retry = Some(path);
+	}
+}
+
+fn save_file_once(p: &FileDialog<'_>) -> Option<(PathBuf, FileSelection)> {
+	let line = save_dialog_line(p, "--getsavefilename")?;
+	let path = PathBuf::from(OsStr::from_bytes(&line));
+	let filter = utils::guess_filter(p, &path, filter_patterns);
+	Some((path, utils::file_selection(p, filter)))
+}
+
+pub fn save_uri(p: &FileDialog<'_>) -> Option<String> {
+	let line = save_dialog_line(p, "--getsaveurl")?;
+	Some(String::from_utf8_lossy(&line).into_owned())
+}
+
+fn save_dialog_line(p: &FileDialog<'_>, kind: &str) -> Option<Vec<u8>> {
+	let filters = filters_string(p);
 	let file_path = utils::abspath(p.path);
 	let file_path = file_path.as_deref().map(Path::as_os_str).unwrap_or(os("."));
 
 	let args = [
 		os("--title"), os(p.title),
-		os("--getsavefilename"), file_path, os(&filters),
+		os(kind), file_path, os(&filters),
 	];
 
 	let (code, output) = invoke_output_bytes("kdialog", &args);
@@ -95,7 +144,7 @@ pub fn save_file(p: &FileDialog<'_>) -> Option<PathBuf> {
 	output
 		.split(|&b| b == b'\n')
 		.find(|line| !line.is_empty())
-		.map(|line| PathBuf::from(OsStr::from_bytes(line)))
+		.map(|line| line.to_vec())
 }
 
 pub fn choose_folder(p: &FileDialog<'_>) -> Option<PathBuf> {
@@ -121,20 +170,23 @@ pub fn choose_folders(p: &FileDialog<'_>) -> Option<Vec<PathBuf>> {
 	choose_folder(p).map(|path| vec![path])
 }
 
-fn filters_string(filters: Option<&[FileFilter<'_>]>) -> String {
+// KDialog always selects the first filter, so the default filter is moved to the front.
+fn filters_string(p: &FileDialog<'_>) -> String {
 	let mut result = String::new();
-	if let Some(filters) = filters {
-		for filter in filters {
-			add_filter(&mut result, filter);
-		}
-		add_filter(&mut result, &FileFilter::ALL_FILES);
+	for filter in &utils::default_first_filters(p) {
+		add_filter(&mut result, filter);
 	}
 	result
 }
 
+// KDialog shows the patterns and matches them case-insensitively, so they are not case-folded.
+fn filter_patterns(filter: &FileFilter<'_>) -> Vec<String> {
+	mime::filter_patterns(filter, utils::GlobSyntax::CaseInsensitiveSpaceSeparated)
+}
+
 fn add_filter(result: &mut String, filter: &FileFilter) {
 	use std::fmt::Write as _;
-	_ = write!(result, "{} ({})\n", filter.name, utils::PrintJoin { parts: filter.patterns, separator: " " });
+	_ = write!(result, "{} ({})\n", filter.name, filter_patterns(filter).join(" "));
 }
 
 
@@ -154,41 +206,247 @@ pub fn text_input(p: &TextInput<'_>) -> Option<String> {
 }
 
 
+pub fn text_viewer(p: &TextViewer<'_>) -> bool {
+	let temp_path;
+	let file_path = match p.content {
+		TextContent::File(path) => path,
+		TextContent::Text(text) => {
+			temp_path = temp_file_path("rustydialogs-kdialog-viewer");
+			if std::fs::write(&temp_path, text.as_bytes()).is_err() {
+				return false;
+			}
+			&temp_path
+		}
+	};
+
+	let status = invoke("kdialog", &[os("--title"), os(p.title), os("--textbox"), file_path.as_os_str(), os("640"), os("480")]);
+	if let TextContent::Text(_) = p.content {
+		let _ = std::fs::remove_file(file_path);
+	}
+	if status != Some(0) {
+		return false;
+	}
+
+	// KDialog text boxes have no checkbox, ask a follow-up question instead.
+	match p.checkbox {
+		Some(checkbox) => invoke("kdialog", &[os("--title"), os(p.title), os("--yesno"), os(checkbox)]) == Some(0),
+		None => true,
+	}
+}
+
+pub fn about_dialog(p: &AboutDialog<'_>) {
+	let title = format!("About {}", p.name);
+	let text = about_html(p);
+
+	let mut args = vec![os("--title"), os(&title)];
+	if !p.icon.is_empty() {
+		args.push(os("--icon"));
+		args.push(os(p.icon));
+	}
+
+	if p.license.is_empty() {
+		args.push(os("--msgbox"));
+		args.push(os(&text));
+		invoke("kdialog", &args);
+		return;
+	}
+
+	// Pressing Esc selects the No button, make it the one that closes the dialog.
+	args.extend([os("--yesno"), os(&text), os("--yes-label"), os("License"), os("--no-label"), os("Close")]);
+	while invoke("kdialog", &args) == Some(0) {
+		text_viewer(&TextViewer {
+			title: "License",
+			content: TextContent::Text(p.license),
+			checkbox: None,
+			owner: p.owner,
+		});
+	}
+}
+
+fn about_html(p: &AboutDialog<'_>) -> String {
+	use std::fmt::Write as _;
+	let mut text = format!("<h3>{} {}</h3>", html_escape(p.name), html_escape(p.version));
+	if !p.comments.is_empty() {
+		_ = write!(text, "<p>{}</p>", html_escape(p.comments));
+	}
+	if !p.website.is_empty() {
+		let website = html_escape(p.website);
+		_ = write!(text, "<p><a href=\"{website}\">{website}</a></p>");
+	}
+	if !p.authors.is_empty() {
+		text.push_str("<p>Authors:");
+		for author in p.authors {
+			_ = write!(text, "<br>{}", html_escape(author));
+		}
+		text.push_str("</p>");
+	}
+	text
+}
+
+fn html_escape(value: &str) -> String {
+	let mut result = String::with_capacity(value.len());
+	for ch in value.chars() {
+		match ch {
+			'&' => result.push_str("&amp;"),
+			'<' => result.push_str("&lt;"),
+			'>' => result.push_str("&gt;"),
+			'"' => result.push_str("&quot;"),
+			'\'' => result.push_str("&#39;"),
+			_ => result.push(ch),
+		}
+	}
+	result
+}
+
+pub fn login_dialog(p: &LoginDialog<'_>) -> Option<Credentials> {
+	// KDialog has no combined login dialog, ask for the user name first and the password second.
+	let (status, username) = invoke_output("kdialog", &[os("--inputbox"), os(p.message), os(p.username), os("--title"), os(p.title)]);
+	if status != Some(0) {
+		return None;
+	}
+
+	let message = format!("Password for {username}:");
+	let (status, password) = invoke_output("kdialog", &[os("--password"), os(&message), os("--title"), os(p.title)]);
+	if status != Some(0) {
+		return None;
+	}
+
+	Some(Credentials { username, password })
+}
+
+
+pub fn print_dialog(_: &PrintDialog<'_>) -> Option<PrintSettings> {
+	// KDialog has no print dialog.
+	None
+}
+
+pub fn app_chooser(p: &AppChooser<'_>) -> Option<String> {
+	let entries = app_chooser_entries(p);
+	let file_name = p.path.file_name().unwrap_or(p.path.as_os_str()).to_string_lossy();
+	let text = format!("Open {file_name} with:");
+	let mut args = vec![os("--menu"), os(&text)];
+	for entry in &entries {
+		args.push(os(&entry.id));
+		args.push(os(&entry.name));
+	}
+	args.push(os("--title"));
+	args.push(os(p.title));
+
+	let (status, output) = invoke_output("kdialog", &args);
+	if status != Some(0) || output.is_empty() {
+		return None;
+	}
+	Some(output)
+}
+
+pub fn icon_picker(p: &IconPicker<'_>) -> Option<IconValue> {
+	let context = match p.context {
+		IconContext::Any => "Any",
+		IconContext::Action => "Action",
+		IconContext::Application => "Application",
+		IconContext::Category => "Category",
+		IconContext::Device => "Device",
+		IconContext::Emblem => "Emblem",
+		IconContext::Emote => "Emote",
+		IconContext::MimeType => "MimeType",
+		IconContext::Place => "Place",
+		IconContext::Status => "StatusIcon",
+	};
+
+	let (status, output) = invoke_output("kdialog", &[os("--geticon"), os("Desktop"), os(context), os("--title"), os(p.title)]);
+	if status != Some(0) || output.is_empty() {
+		return None;
+	}
+
+	// Icons browsed from the file system are returned as absolute paths.
+	if output.starts_with('/') {
+		Some(IconValue::File(PathBuf::from(output)))
+	}
+	else {
+		Some(IconValue::Name(output))
+	}
+}
+
+// KDialog has no streaming text box, collect the log and show it when waiting for the window to close.
+pub struct LogWindowImpl {
+	title: String,
+	log: String,
+}
+
+impl LogWindowImpl {
+	pub fn new(title: &str) -> LogWindowImpl {
+		LogWindowImpl { title: title.to_string(), log: String::new() }
+	}
+
+	pub fn append_line(&mut self, line: &str) {
+		self.log.push_str(line);
+		self.log.push('\n');
+	}
+
+	pub fn set_title(&mut self, title: &str) {
+		self.title = title.to_string();
+	}
+
+	pub fn finish(&mut self) {
+	}
+
+	pub fn wait_closed(self) {
+		text_viewer(&TextViewer {
+			title: &self.title,
+			content: TextContent::Text(&self.log),
+			checkbox: None,
+			owner: None,
+		});
+	}
+}
 
 pub fn color_picker(p: &ColorPicker<'_>) -> Option<ColorValue> {
-	let value = format_color(p.value);
+	let value = format_color(p.value, p.alpha);
 	let (status, output) = invoke_output("kdialog", &[os("--getcolor"), os(&value), os("--title"), os(p.title)]);
 
 	if status != Some(0) {
 		return None;
 	}
 
-	let color = parse_color(&output).unwrap_or_else(|| panic!("kdialog color_picker returned invalid color: {output}"));
+	let initial_alpha = if p.alpha { p.value.alpha } else { 255 };
+	let color = parse_color(&output, p.alpha, initial_alpha).unwrap_or_else(|| panic!("kdialog color_picker returned invalid color: {output}"));
 	Some(color)
 }
 
-fn format_color(color: ColorValue) -> String {
-	format!("#{:02X}{:02X}{:02X}", color.red, color.green, color.blue)
+// Qt names colors with alpha as `#AARRGGBB` rather than the CSS `#RRGGBBAA`.
+fn format_color(color: ColorValue, alpha: bool) -> String {
+	let opaque = ColorValue { alpha: 255, ..color };
+	if alpha && color.alpha != 255 {
+		format!("#{:02X}{}", color.alpha, opaque.to_string().trim_start_matches('#'))
+	}
+	else {
+		opaque.to_string()
+	}
 }
 
-fn parse_color(value: &str) -> Option<ColorValue> {
+// Qt only reports `#AARRGGBB` for translucent colors, otherwise the color keeps the initial alpha.
+fn parse_color(value: &str, alpha: bool, initial_alpha: u8) -> Option<ColorValue> {
 	let value = value.trim();
-	let value = value.strip_prefix('#').unwrap_or(value);
-	if value.len() != 6 && value.len() != 8 {
-		return None;
-	}
-	if !value.is_ascii() {
+	let color: ColorValue = match value.strip_prefix('#') {
+		Some(hex) if hex.len() == 8 && hex.is_ascii() => format!("#{}{}", &hex[2..], &hex[..2]).parse().ok()?,
+		_ => ColorValue { alpha: initial_alpha, ..value.parse().ok()? },
+	};
+	// The alpha the dialog reports is ignored when the picker has no alpha.
+	Some(if alpha { color } else { ColorValue { alpha: initial_alpha, ..color } })
+}
+
+pub fn font_picker(p: &FontPicker<'_>) -> Option<FontValue> {
+	// KDialog has no font chooser, ask for a font description string instead.
+	let (status, output) = invoke_output("kdialog", &[os("--inputbox"), os("Font (e.g. \"Sans Bold Italic 12\"):"), os(p.value), os("--title"), os(p.title)]);
+	if status != Some(0) {
 		return None;
 	}
 
-	let red = u8::from_str_radix(&value[0..2], 16).ok()?;
-	let green = u8::from_str_radix(&value[2..4], 16).ok()?;
-	let blue = u8::from_str_radix(&value[4..6], 16).ok()?;
-	if value.len() == 8 {
-		u8::from_str_radix(&value[6..8], 16).ok()?;
+	let font = utils::parse_font(&output);
+	if font.family.is_empty() {
+		return None;
 	}
-
-	Some(ColorValue { red, green, blue })
+	Some(font)
 }
 
 
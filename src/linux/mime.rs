@@ -0,0 +1,262 @@
+use std::ffi::OsString;
+use std::fs;
+
+use super::*;
+
+// An application from a `.desktop` file.
+pub struct DesktopEntry {
+	// The desktop file id, eg. `org.gnome.TextEditor.desktop`.
+	pub id: String,
+	pub name: String,
+	pub exec: String,
+	mime_types: Vec<String>,
+	hidden: bool,
+}
+
+// Guesses the MIME type of a file.
+pub fn mime_type(path: &Path) -> Option<String> {
+	let queries: [(&str, &[&OsStr]); 2] = [
+		("xdg-mime", &[os("query"), os("filetype"), path.as_os_str()]),
+		("file", &[os("--brief"), os("--mime-type"), os("--"), path.as_os_str()]),
+	];
+	for (program, args) in queries {
+		let Ok(output) = process::Command::new(program).args(args).output() else { continue };
+		let mime = String::from_utf8_lossy(&output.stdout).trim().to_string();
+		if output.status.success() && mime.contains('/') {
+			return Some(mime);
+		}
+	}
+	None
+}
+
+// The space separated patterns of a filter with its MIME types translated to glob patterns, for backends which only support patterns.
+pub fn filter_patterns(filter: &FileFilter<'_>, syntax: utils::GlobSyntax) -> Vec<String> {
+	let mut patterns = filter.patterns.iter().map(|&pattern| pattern.to_string()).collect::<Vec<_>>();
+	for &mime_type in filter.mime_types {
+		patterns.extend(globs(mime_type));
+	}
+	utils::glob_patterns(patterns.iter().map(String::as_str), syntax)
+}
+
+// Looks up the glob patterns of a MIME type in the shared-mime-info database.
+// A MIME type ending with `/*` matches all types of the media type, eg. `image/*`.
+pub fn globs(mime_type: &str) -> Vec<String> {
+	let mime_type = canonical_mime_type(mime_type);
+	let matches = |candidate: &str| match mime_type.strip_suffix("/*") {
+		Some(media_type) => candidate.split_once('/').is_some_and(|(candidate, _)| candidate == media_type),
+		None => candidate == mime_type,
+	};
+
+	let mut globs = Vec::new();
+	for dir in xdg_data_dirs() {
+		let mime_dir = dir.join("mime");
+		// The globs2 file has the format `weight:mime/type:glob[:flags]`, the older globs file `mime/type:glob`.
+		let (contents, fields) = match fs::read_to_string(mime_dir.join("globs2")) {
+			Ok(contents) => (contents, 1),
+			Err(_) => match fs::read_to_string(mime_dir.join("globs")) {
+				Ok(contents) => (contents, 0),
+				Err(_) => continue,
+			},
+		};
+		for line in contents.lines() {
+			if line.starts_with('#') {
+				continue;
+			}
+			let mut parts = line.split(':').skip(fields);
+			let (Some(candidate), Some(glob)) = (parts.next(), parts.next()) else { continue };
+			if matches(candidate) && !globs.iter().any(|existing| existing == glob) {
+				globs.push(glob.to_string());
+			}
+		}
+	}
+	globs
+}
+
+// Resolves an alias from the shared-mime-info database, eg. `application/x-pdf` to `application/pdf`.
+fn canonical_mime_type(mime_type: &str) -> String {
+	for dir in xdg_data_dirs() {
+		let Ok(contents) = fs::read_to_string(dir.join("mime/aliases")) else { continue };
+		let canonical = contents.lines()
+			.filter_map(|line| line.split_once(' '))
+			.find_map(|(alias, canonical)| (alias == mime_type).then_some(canonical));
+		if let Some(canonical) = canonical {
+			return canonical.to_string();
+		}
+	}
+	mime_type.to_string()
+}
+
+// Lists the applications which can open the MIME type, sorted by name.
+// Falls back to all applications which open files when none are registered for the MIME type.
+pub fn applications(mime_type: &str) -> Vec<DesktopEntry> {
+	let mut entries: Vec<DesktopEntry> = Vec::new();
+	for dir in application_dirs() {
+		collect_entries(&dir, &dir, &mut entries);
+	}
+	entries.retain(|entry| !entry.hidden);
+
+	if entries.iter().any(|entry| entry.mime_types.iter().any(|mime| mime == mime_type)) {
+		entries.retain(|entry| entry.mime_types.iter().any(|mime| mime == mime_type));
+	}
+	else {
+		entries.retain(|entry| !entry.mime_types.is_empty());
+	}
+
+	entries.sort_by_cached_key(|entry| entry.name.to_lowercase());
+	entries
+}
+
+// Finds an application by its desktop file id.
+pub fn find_application(id: &str) -> Option<DesktopEntry> {
+	let mut entries = Vec::new();
+	for dir in application_dirs() {
+		collect_entries(&dir, &dir, &mut entries);
+	}
+	entries.into_iter().find(|entry| entry.id == id && !entry.hidden)
+}
+
+// Opens the file with the application.
+#[allow(clippy::zombie_processes)]
+pub fn launch(entry: &DesktopEntry, path: &Path) -> bool {
+	let args = exec_args(&entry.exec, path);
+	let Some((program, args)) = args.split_first() else {
+		return false;
+	};
+	// The application outlives the dialog, the child handle is intentionally not waited on.
+	process::Command::new(program).args(args).spawn().is_ok()
+}
+
+fn application_dirs() -> Vec<PathBuf> {
+	xdg_data_dirs().into_iter().map(|dir| dir.join("applications")).collect()
+}
+
+fn collect_entries(root: &Path, dir: &Path, entries: &mut Vec<DesktopEntry>) {
+	let Ok(read_dir) = fs::read_dir(dir) else { return };
+	for item in read_dir.flatten() {
+		let path = item.path();
+		if path.is_dir() {
+			collect_entries(root, &path, entries);
+			continue;
+		}
+		if path.extension() != Some(OsStr::new("desktop")) {
+			continue;
+		}
+
+		// The id is the path relative to the applications directory with `/` replaced by `-`.
+		let Ok(relative) = path.strip_prefix(root) else { continue };
+		let id = relative.to_string_lossy().replace('/', "-");
+
+		// Entries from earlier directories take precedence, even when they are hidden.
+		if entries.iter().any(|entry| entry.id == id) {
+			continue;
+		}
+		let Ok(contents) = fs::read_to_string(&path) else { continue };
+		entries.push(parse_entry(id, &contents));
+	}
+}
+
+fn parse_entry(id: String, contents: &str) -> DesktopEntry {
+	let mut in_group = false;
+	let mut name = None;
+	let mut exec = None;
+	let mut mime_types = Vec::new();
+	let mut hidden = false;
+	let mut application = false;
+
+	for line in contents.lines() {
+		let line = line.trim();
+		if line.starts_with('[') {
+			in_group = line == "[Desktop Entry]";
+			continue;
+		}
+		if !in_group {
+			continue;
+		}
+		let Some((key, value)) = line.split_once('=') else { continue };
+		match key.trim() {
+			"Type" => application = value.trim() == "Application",
+			"Name" => name = Some(value.trim().to_string()),
+			"Exec" => exec = Some(value.trim().to_string()),
+			"MimeType" => mime_types = value.split(';').map(str::trim).filter(|mime| !mime.is_empty()).map(String::from).collect(),
+			"NoDisplay" | "Hidden" => hidden |= value.trim() == "true",
+			_ => {}
+		}
+	}
+
+	// Entries which cannot be launched are kept as hidden so they still shadow later directories.
+	let hidden = hidden || !application || exec.is_none();
+	let name = name.unwrap_or_else(|| id.trim_end_matches(".desktop").to_string());
+	DesktopEntry { id, name, exec: exec.unwrap_or_default(), mime_types, hidden }
+}
+
+// Splits the Exec key into arguments and expands the field codes with the file path.
+fn exec_args(exec: &str, path: &Path) -> Vec<OsString> {
+	let mut args = Vec::new();
+	let mut has_file = false;
+
+	for token in exec_tokens(exec) {
+		match token.as_str() {
+			"%f" | "%F" | "%u" | "%U" => {
+				args.push(path.as_os_str().to_os_string());
+				has_file = true;
+			}
+			"%i" | "%c" | "%k" => {}
+			_ => {
+				let mut arg = String::new();
+				let mut chars = token.chars();
+				while let Some(chr) = chars.next() {
+					if chr != '%' {
+						arg.push(chr);
+						continue;
+					}
+					// Deprecated and unknown field codes are removed.
+					if chars.next() == Some('%') {
+						arg.push('%');
+					}
+				}
+				args.push(OsString::from(arg));
+			}
+		}
+	}
+
+	if !has_file && !args.is_empty() {
+		args.push(path.as_os_str().to_os_string());
+	}
+	args
+}
+
+fn exec_tokens(exec: &str) -> Vec<String> {
+	let mut tokens = Vec::new();
+	let mut token = String::new();
+	let mut in_token = false;
+	let mut quoted = false;
+	let mut chars = exec.chars();
+
+	while let Some(chr) = chars.next() {
+		match chr {
+			'"' => {
+				quoted = !quoted;
+				in_token = true;
+			}
+			'\\' if quoted => {
+				if let Some(next) = chars.next() {
+					token.push(next);
+				}
+			}
+			' ' | '\t' if !quoted => {
+				if in_token {
+					tokens.push(std::mem::take(&mut token));
+					in_token = false;
+				}
+			}
+			_ => {
+				token.push(chr);
+				in_token = true;
+			}
+		}
+	}
+	if in_token {
+		tokens.push(token);
+	}
+	tokens
+}
@@ -6,6 +6,8 @@ use super::*;
 
 mod kdialog;
 mod zenity;
+mod icons;
+mod mime;
 
 #[cfg(feature = "libnotify")]
 mod notify;
@@ -107,7 +109,7 @@ pub fn message_box(p: &MessageBox<'_>) -> Option<MessageResult> {
 	}
 }
 
-pub fn pick_file(p: &FileDialog<'_>) -> Option<path::PathBuf> {
+pub fn pick_file(p: &FileDialog<'_>) -> Option<(path::PathBuf, FileSelection)> {
 	match *BACKEND {
 		Backend::KDialog => kdialog::pick_file(p),
 		Backend::Zenity => zenity::pick_file(p),
@@ -120,7 +122,7 @@ pub fn pick_file(p: &FileDialog<'_>) -> Option<path::PathBuf> {
 	}
 }
 
-pub fn pick_files(p: &FileDialog<'_>) -> Option<Vec<path::PathBuf>> {
+pub fn pick_files(p: &FileDialog<'_>) -> Option<(Vec<path::PathBuf>, FileSelection)> {
 	match *BACKEND {
 		Backend::KDialog => kdialog::pick_files(p),
 		Backend::Zenity => zenity::pick_files(p),
@@ -133,7 +135,7 @@ pub fn pick_files(p: &FileDialog<'_>) -> Option<Vec<path::PathBuf>> {
 	}
 }
 
-pub fn save_file(p: &FileDialog<'_>) -> Option<path::PathBuf> {
+pub fn save_file(p: &FileDialog<'_>) -> Option<(path::PathBuf, FileSelection)> {
 	match *BACKEND {
 		Backend::KDialog => kdialog::save_file(p),
 		Backend::Zenity => zenity::save_file(p),
@@ -146,6 +148,53 @@ pub fn save_file(p: &FileDialog<'_>) -> Option<path::PathBuf> {
 	}
 }
 
+pub fn pick_uri(p: &FileDialog<'_>) -> Option<String> {
+	match *BACKEND {
+		Backend::KDialog => kdialog::pick_uri(p),
+		Backend::Zenity => utils::pick_uri(p, zenity::pick_file),
+		#[cfg(feature = "xdg-portal")]
+		Backend::XdgPortal => xdg_portal::pick_uri(p),
+		#[cfg(feature = "gtk3")]
+		Backend::Gtk3 => gtk3::pick_uri(p),
+		#[cfg(feature = "gtk4")]
+		Backend::Gtk4 => gtk4::pick_uri(p),
+	}
+}
+
+pub fn pick_uris(p: &FileDialog<'_>) -> Option<Vec<String>> {
+	match *BACKEND {
+		Backend::KDialog => kdialog::pick_uris(p),
+		Backend::Zenity => utils::pick_uris(p, zenity::pick_files),
+		#[cfg(feature = "xdg-portal")]
+		Backend::XdgPortal => xdg_portal::pick_uris(p),
+		#[cfg(feature = "gtk3")]
+		Backend::Gtk3 => gtk3::pick_uris(p),
+		#[cfg(feature = "gtk4")]
+		Backend::Gtk4 => gtk4::pick_uris(p),
+	}
+}
+
+pub fn save_files(p: &FileDialog<'_>, names: &[&str]) -> Option<Vec<path::PathBuf>> {
+	match *BACKEND {
+		#[cfg(feature = "xdg-portal")]
+		Backend::XdgPortal => xdg_portal::save_files(p, names),
+		_ => utils::save_files(p, names),
+	}
+}
+
+pub fn save_uri(p: &FileDialog<'_>) -> Option<String> {
+	match *BACKEND {
+		Backend::KDialog => kdialog::save_uri(p),
+		Backend::Zenity => utils::save_uri(p),
+		#[cfg(feature = "xdg-portal")]
+		Backend::XdgPortal => xdg_portal::save_uri(p),
+		#[cfg(feature = "gtk3")]
+		Backend::Gtk3 => gtk3::save_uri(p),
+		#[cfg(feature = "gtk4")]
+		Backend::Gtk4 => gtk4::save_uri(p),
+	}
+}
+
 pub fn choose_folder(p: &FileDialog<'_>) -> Option<path::PathBuf> {
 	match *BACKEND {
 		Backend::KDialog => kdialog::choose_folder(p),
@@ -185,6 +234,45 @@ pub fn text_input(p: &TextInput<'_>) -> Option<String> {
 	}
 }
 
+pub fn text_viewer(p: &TextViewer<'_>) -> bool {
+	match *BACKEND {
+		Backend::KDialog => kdialog::text_viewer(p),
+		Backend::Zenity => zenity::text_viewer(p),
+		#[cfg(feature = "xdg-portal")]
+		Backend::XdgPortal => xdg_portal::text_viewer(p),
+		#[cfg(feature = "gtk3")]
+		Backend::Gtk3 => gtk3::text_viewer(p),
+		#[cfg(feature = "gtk4")]
+		Backend::Gtk4 => gtk4::text_viewer(p),
+	}
+}
+
+pub fn login_dialog(p: &LoginDialog<'_>) -> Option<Credentials> {
+	match *BACKEND {
+		Backend::KDialog => kdialog::login_dialog(p),
+		Backend::Zenity => zenity::login_dialog(p),
+		#[cfg(feature = "xdg-portal")]
+		Backend::XdgPortal => xdg_portal::login_dialog(p),
+		#[cfg(feature = "gtk3")]
+		Backend::Gtk3 => gtk3::login_dialog(p),
+		#[cfg(feature = "gtk4")]
+		Backend::Gtk4 => gtk4::login_dialog(p),
+	}
+}
+
+pub fn about_dialog(p: &AboutDialog<'_>) {
+	match *BACKEND {
+		Backend::KDialog => kdialog::about_dialog(p),
+		Backend::Zenity => zenity::about_dialog(p),
+		#[cfg(feature = "xdg-portal")]
+		Backend::XdgPortal => xdg_portal::about_dialog(p),
+		#[cfg(feature = "gtk3")]
+		Backend::Gtk3 => gtk3::about_dialog(p),
+		#[cfg(feature = "gtk4")]
+		Backend::Gtk4 => gtk4::about_dialog(p),
+	}
+}
+
 pub fn color_picker(p: &ColorPicker<'_>) -> Option<ColorValue> {
 	match *BACKEND {
 		Backend::KDialog => kdialog::color_picker(p),
@@ -198,6 +286,210 @@ pub fn color_picker(p: &ColorPicker<'_>) -> Option<ColorValue> {
 	}
 }
 
+pub fn pick_color_from_screen(p: &ColorPicker<'_>) -> Option<ColorValue> {
+	// Only the portal can read the screen contents, also under Wayland.
+	#[cfg(feature = "xdg-portal")] {
+		xdg_portal::color_picker(p)
+	}
+	#[cfg(not(feature = "xdg-portal"))] {
+		let _ = p;
+		None
+	}
+}
+
+pub fn font_picker(p: &FontPicker<'_>) -> Option<FontValue> {
+	match *BACKEND {
+		Backend::KDialog => kdialog::font_picker(p),
+		Backend::Zenity => zenity::font_picker(p),
+		#[cfg(feature = "xdg-portal")]
+		Backend::XdgPortal => xdg_portal::font_picker(p),
+		#[cfg(feature = "gtk3")]
+		Backend::Gtk3 => gtk3::font_picker(p),
+		#[cfg(feature = "gtk4")]
+		Backend::Gtk4 => gtk4::font_picker(p),
+	}
+}
+
+pub fn print_dialog(p: &PrintDialog<'_>) -> Option<PrintSettings> {
+	match *BACKEND {
+		Backend::KDialog => kdialog::print_dialog(p),
+		Backend::Zenity => zenity::print_dialog(p),
+		#[cfg(feature = "xdg-portal")]
+		Backend::XdgPortal => xdg_portal::print_dialog(p),
+		#[cfg(feature = "gtk3")]
+		Backend::Gtk3 => gtk3::print_dialog(p),
+		#[cfg(feature = "gtk4")]
+		Backend::Gtk4 => gtk4::print_dialog(p),
+	}
+}
+
+pub fn print_file(settings: &PrintSettings, title: &str, path: &path::Path) -> bool {
+	match settings.token {
+		#[cfg(feature = "xdg-portal")]
+		Some(token) => xdg_portal::print_file(token, title, path),
+		_ => print_file_lp(settings, title, path),
+	}
+}
+
+fn print_file_lp(settings: &PrintSettings, title: &str, path: &path::Path) -> bool {
+	let copies = settings.copies.max(1).to_string();
+	let mut args = vec![os("-t"), os(title), os("-n"), os(&copies)];
+
+	if !settings.printer.is_empty() {
+		args.push(os("-d"));
+		args.push(os(&settings.printer));
+	}
+
+	let pages = settings.page_ranges.iter()
+		.map(|&(first, last)| if first == last { first.to_string() } else { format!("{first}-{last}") })
+		.collect::<Vec<_>>()
+		.join(",");
+	if !pages.is_empty() {
+		args.push(os("-P"));
+		args.push(os(&pages));
+	}
+
+	let media = format!("media={}", settings.paper);
+	if !settings.paper.is_empty() {
+		args.push(os("-o"));
+		args.push(os(&media));
+	}
+
+	args.push(os("-o"));
+	args.push(os(match settings.orientation {
+		PageOrientation::Portrait => "orientation-requested=3",
+		PageOrientation::Landscape => "orientation-requested=4",
+		PageOrientation::ReverseLandscape => "orientation-requested=5",
+		PageOrientation::ReversePortrait => "orientation-requested=6",
+	}));
+
+	args.push(os("--"));
+	args.push(path.as_os_str());
+
+	process::Command::new("lp").args(&args).stdout(process::Stdio::null()).status().is_ok_and(|status| status.success())
+}
+
+pub fn app_chooser(p: &AppChooser<'_>) -> Option<String> {
+	match *BACKEND {
+		Backend::KDialog => kdialog::app_chooser(p),
+		Backend::Zenity => zenity::app_chooser(p),
+		#[cfg(feature = "xdg-portal")]
+		Backend::XdgPortal => xdg_portal::app_chooser(p),
+		#[cfg(feature = "gtk3")]
+		Backend::Gtk3 => gtk3::app_chooser(p),
+		#[cfg(feature = "gtk4")]
+		Backend::Gtk4 => gtk4::app_chooser(p),
+	}
+}
+
+pub fn app_chooser_open(p: &AppChooser<'_>) -> bool {
+	match *BACKEND {
+		#[cfg(feature = "xdg-portal")]
+		Backend::XdgPortal => xdg_portal::app_chooser_open(p),
+		_ => app_chooser(p).and_then(|id| mime::find_application(&id)).is_some_and(|app| mime::launch(&app, p.path)),
+	}
+}
+
+// Lists the applications for the zenity and kdialog backends.
+fn app_chooser_entries(p: &AppChooser<'_>) -> Vec<mime::DesktopEntry> {
+	let mime_type = match p.mime_type {
+		Some(mime_type) => mime_type.to_string(),
+		None => mime::mime_type(p.path).unwrap_or_else(|| String::from("application/octet-stream")),
+	};
+	mime::applications(&mime_type)
+}
+
+pub fn icon_picker(p: &IconPicker<'_>) -> Option<IconValue> {
+	match *BACKEND {
+		Backend::KDialog => kdialog::icon_picker(p),
+		Backend::Zenity => zenity::icon_picker(p),
+		#[cfg(feature = "xdg-portal")]
+		Backend::XdgPortal => xdg_portal::icon_picker(p),
+		#[cfg(feature = "gtk3")]
+		Backend::Gtk3 => gtk3::icon_picker(p),
+		#[cfg(feature = "gtk4")]
+		Backend::Gtk4 => gtk4::icon_picker(p),
+	}
+}
+
+pub enum LogWindowImpl {
+	KDialog(kdialog::LogWindowImpl),
+	Zenity(zenity::LogWindowImpl),
+	#[cfg(feature = "xdg-portal")]
+	XdgPortal,
+	#[cfg(feature = "gtk3")]
+	Gtk3(gtk3::LogWindowImpl),
+	#[cfg(feature = "gtk4")]
+	Gtk4(gtk4::LogWindowImpl),
+}
+
+impl LogWindowImpl {
+	pub fn new(title: &str) -> LogWindowImpl {
+		match *BACKEND {
+			Backend::KDialog => LogWindowImpl::KDialog(kdialog::LogWindowImpl::new(title)),
+			Backend::Zenity => LogWindowImpl::Zenity(zenity::LogWindowImpl::new(title)),
+			#[cfg(feature = "xdg-portal")]
+			Backend::XdgPortal => LogWindowImpl::XdgPortal,
+			#[cfg(feature = "gtk3")]
+			Backend::Gtk3 => LogWindowImpl::Gtk3(gtk3::LogWindowImpl::new(title)),
+			#[cfg(feature = "gtk4")]
+			Backend::Gtk4 => LogWindowImpl::Gtk4(gtk4::LogWindowImpl::new(title)),
+		}
+	}
+
+	pub fn append_line(&mut self, line: &str) {
+		match self {
+			LogWindowImpl::KDialog(window) => window.append_line(line),
+			LogWindowImpl::Zenity(window) => window.append_line(line),
+			#[cfg(feature = "xdg-portal")]
+			LogWindowImpl::XdgPortal => {}
+			#[cfg(feature = "gtk3")]
+			LogWindowImpl::Gtk3(window) => window.append_line(line),
+			#[cfg(feature = "gtk4")]
+			LogWindowImpl::Gtk4(window) => window.append_line(line),
+		}
+	}
+
+	pub fn set_title(&mut self, title: &str) {
+		match self {
+			LogWindowImpl::KDialog(window) => window.set_title(title),
+			LogWindowImpl::Zenity(window) => window.set_title(title),
+			#[cfg(feature = "xdg-portal")]
+			LogWindowImpl::XdgPortal => {}
+			#[cfg(feature = "gtk3")]
+			LogWindowImpl::Gtk3(window) => window.set_title(title),
+			#[cfg(feature = "gtk4")]
+			LogWindowImpl::Gtk4(window) => window.set_title(title),
+		}
+	}
+
+	pub fn finish(&mut self) {
+		match self {
+			LogWindowImpl::KDialog(window) => window.finish(),
+			LogWindowImpl::Zenity(window) => window.finish(),
+			#[cfg(feature = "xdg-portal")]
+			LogWindowImpl::XdgPortal => {}
+			#[cfg(feature = "gtk3")]
+			LogWindowImpl::Gtk3(window) => window.finish(),
+			#[cfg(feature = "gtk4")]
+			LogWindowImpl::Gtk4(window) => window.finish(),
+		}
+	}
+
+	pub fn wait_closed(self) {
+		match self {
+			LogWindowImpl::KDialog(window) => window.wait_closed(),
+			LogWindowImpl::Zenity(window) => window.wait_closed(),
+			#[cfg(feature = "xdg-portal")]
+			LogWindowImpl::XdgPortal => {}
+			#[cfg(feature = "gtk3")]
+			LogWindowImpl::Gtk3(window) => window.wait_closed(),
+			#[cfg(feature = "gtk4")]
+			LogWindowImpl::Gtk4(window) => window.wait_closed(),
+		}
+	}
+}
+
 #[inline]
 pub fn notify_setup(app_id: &str) -> bool {
 	#[cfg(feature = "libnotify")] {
@@ -234,25 +526,105 @@ pub fn notify(p: &Notification<'_>) {
 	}
 }
 
+pub fn desktop_appearance() -> Appearance {
+	#[cfg(feature = "xdg-portal")]
+	if let Some(appearance) = xdg_portal::appearance() {
+		return appearance;
+	}
+
+	// Only look at the backend with GTK, it panics when no dialog program is installed otherwise.
+	#[cfg(any(feature = "gtk3", feature = "gtk4"))]
+	match *BACKEND {
+		#[cfg(feature = "gtk3")]
+		Backend::Gtk3 => return gtk3::appearance(),
+		#[cfg(feature = "gtk4")]
+		Backend::Gtk4 => return gtk4::appearance(),
+		_ => {}
+	}
+
+	gsettings_appearance()
+}
+
+// Reads the GNOME interface settings, which other desktops often mirror for GTK applications.
+fn gsettings_appearance() -> Appearance {
+	let gsettings = |schema: &str, key: &str| {
+		let output = process::Command::new("gsettings").args(["get", schema, key]).output().ok()?;
+		let value = String::from_utf8(output.stdout).ok()?;
+		output.status.success().then(|| value.trim().trim_matches('\'').to_string())
+	};
+
+	let color_scheme = match gsettings("org.gnome.desktop.interface", "color-scheme").as_deref() {
+		Some("prefer-dark") => ColorScheme::Dark,
+		Some("prefer-light") => ColorScheme::Light,
+		_ => gtk_theme_color_scheme(env::var("GTK_THEME").ok().as_deref().unwrap_or_default()),
+	};
+	let high_contrast = gsettings("org.gnome.desktop.a11y.interface", "high-contrast").as_deref() == Some("true");
+
+	Appearance { color_scheme, accent_color: None, high_contrast }
+}
+
+// Dark variants of GTK themes are named like `Adwaita-dark` or `Adwaita:dark`.
+fn gtk_theme_color_scheme(theme: &str) -> ColorScheme {
+	let theme = theme.to_ascii_lowercase();
+	if theme.ends_with("-dark") || theme.ends_with(":dark") {
+		ColorScheme::Dark
+	}
+	else {
+		ColorScheme::NoPreference
+	}
+}
+
 #[inline]
 fn os(s: &str) -> &OsStr {
 	OsStr::new(s)
 }
 
+// Starts zenity with the forced color scheme, the GTK theme variant is picked through the environment.
+fn command(program: &str) -> process::Command {
+	let mut command = process::Command::new(program);
+	if program != "zenity" {
+		return command;
+	}
+
+	let color_scheme = utils::color_scheme();
+	if color_scheme == ColorScheme::NoPreference {
+		return command;
+	}
+
+	let theme = env::var("GTK_THEME").ok()
+		.or_else(|| {
+			let output = process::Command::new("gsettings").args(["get", "org.gnome.desktop.interface", "gtk-theme"]).output().ok()?;
+			output.status.success().then(|| String::from_utf8_lossy(&output.stdout).trim().trim_matches('\'').to_string())
+		})
+		.filter(|theme| !theme.is_empty())
+		.unwrap_or_else(|| String::from("Adwaita"));
+	let theme = theme.strip_suffix(":dark").unwrap_or(&theme);
+	let theme = theme.strip_suffix("-dark").unwrap_or(theme);
+
+	// Zenity 4 uses libadwaita, which ignores GTK_THEME but has its own override.
+	if color_scheme == ColorScheme::Dark {
+		command.env("GTK_THEME", format!("{theme}:dark")).env("ADW_DEBUG_COLOR_SCHEME", "force-dark");
+	}
+	else {
+		command.env("GTK_THEME", theme).env("ADW_DEBUG_COLOR_SCHEME", "force-light");
+	}
+	command
+}
+
 #[track_caller]
 fn invoke(program: &str, args: &[&OsStr]) -> Option<i32> {
-	let mut child = process::Command::new(program).args(args).spawn().expect("failed to spawn process");
+	let mut child = command(program).args(args).spawn().expect("failed to spawn process");
 	child.wait().expect("failed to wait for process").code()
 }
 
 #[track_caller]
 fn invoke_async(program: &str, args: &[&OsStr]) {
-	let _ = process::Command::new(program).args(args).spawn().expect("failed to spawn process");
+	let _ = command(program).args(args).spawn().expect("failed to spawn process");
 }
 
 #[track_caller]
 fn invoke_output(program: &str, args: &[&OsStr]) -> (Option<i32>, String) {
-	let output = process::Command::new(program).args(args).output().expect("failed to spawn process");
+	let output = command(program).args(args).output().expect("failed to spawn process");
 	let mut stdout = String::from_utf8(output.stdout).expect("failed to parse stdout as UTF-8");
 	if stdout.ends_with('\n') {
 		stdout.pop();
@@ -263,10 +635,33 @@ fn invoke_output(program: &str, args: &[&OsStr]) -> (Option<i32>, String) {
 
 #[track_caller]
 fn invoke_output_bytes(program: &str, args: &[&OsStr]) -> (Option<i32>, Vec<u8>) {
-	let output = process::Command::new(program).args(args).output().expect("failed to spawn process");
+	let output = command(program).args(args).output().expect("failed to spawn process");
 	(output.status.code(), output.stdout)
 }
 
+// The XDG data home followed by the XDG data directories, in order of precedence.
+fn xdg_data_dirs() -> Vec<PathBuf> {
+	let data_home = env::var_os("XDG_DATA_HOME")
+		.filter(|dir| !dir.is_empty())
+		.map(PathBuf::from)
+		.or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share")));
+	let data_dirs = env::var("XDG_DATA_DIRS")
+		.ok()
+		.filter(|dirs| !dirs.is_empty())
+		.unwrap_or_else(|| String::from("/usr/local/share:/usr/share"));
+
+	data_home.into_iter()
+		.chain(data_dirs.split(':').filter(|dir| !dir.is_empty()).map(PathBuf::from))
+		.collect()
+}
+
+fn temp_file_path(prefix: &str) -> PathBuf {
+	let nanos = std::time::SystemTime::now()
+		.duration_since(std::time::UNIX_EPOCH)
+		.map_or(0, |d| d.as_nanos());
+	env::temp_dir().join(format!("{prefix}-{}-{nanos}.txt", process::id()))
+}
+
 #[track_caller]
 fn exit_status_error(status: Option<i32>) -> ! {
 	if let Some(code) = status {
@@ -1,9 +1,11 @@
 use std::collections::HashMap;
+use std::fs;
+use std::os::fd::IntoRawFd;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::mpsc;
 use std::{thread, time};
 
-use dbus::arg::{PropMap, RefArg, Variant};
+use dbus::arg::{ArgType, OwnedFd, PropMap, RefArg, Variant};
 use dbus::blocking::Connection;
 use dbus::message::MatchRule;
 
@@ -13,7 +15,12 @@ const DESKTOP_BUS_NAME: &str = "org.freedesktop.portal.Desktop";
 const DESKTOP_PATH: &str = "/org/freedesktop/portal/desktop";
 const FILE_CHOOSER_INTERFACE: &str = "org.freedesktop.portal.FileChooser";
 const NOTIFICATION_INTERFACE: &str = "org.freedesktop.portal.Notification";
+const OPEN_URI_INTERFACE: &str = "org.freedesktop.portal.OpenURI";
+const PRINT_INTERFACE: &str = "org.freedesktop.portal.Print";
 const REQUEST_INTERFACE: &str = "org.freedesktop.portal.Request";
+const SCREENSHOT_INTERFACE: &str = "org.freedesktop.portal.Screenshot";
+const SETTINGS_INTERFACE: &str = "org.freedesktop.portal.Settings";
+const APPEARANCE_NAMESPACE: &str = "org.freedesktop.appearance";
 
 static NEXT_NOTIFICATION_ID: AtomicU64 = AtomicU64::new(1);
 
@@ -21,15 +28,40 @@ pub fn message_box(_: &MessageBox<'_>) -> Option<MessageResult> {
 	None
 }
 
-pub fn pick_file(p: &FileDialog<'_>) -> Option<PathBuf> {
-	pick_files_impl(p, false).and_then(|paths| paths.into_iter().next())
+pub fn pick_file(p: &FileDialog<'_>) -> Option<(PathBuf, FileSelection)> {
+	let (paths, filter) = pick_files_impl(p, false)?;
+	Some((paths.into_iter().next()?, filter))
 }
 
-pub fn pick_files(p: &FileDialog<'_>) -> Option<Vec<PathBuf>> {
+pub fn pick_files(p: &FileDialog<'_>) -> Option<(Vec<PathBuf>, FileSelection)> {
 	pick_files_impl(p, true)
 }
 
-fn pick_files_impl(p: &FileDialog<'_>, multiple: bool) -> Option<Vec<PathBuf>> {
+fn pick_files_impl(p: &FileDialog<'_>, multiple: bool) -> Option<(Vec<PathBuf>, FileSelection)> {
+	let (uris, selection) = pick_uris_impl(p, multiple)?;
+	let paths = uris
+		.into_iter()
+		.filter_map(|uri| parse_file_uri(&uri))
+		.collect::<Vec<_>>();
+
+	if paths.is_empty() {
+		None
+	}
+	else {
+		Some((paths, selection))
+	}
+}
+
+pub fn pick_uri(p: &FileDialog<'_>) -> Option<String> {
+	let (uris, _) = pick_uris_impl(p, false)?;
+	uris.into_iter().next()
+}
+
+pub fn pick_uris(p: &FileDialog<'_>) -> Option<Vec<String>> {
+	pick_uris_impl(p, true).map(|(uris, _)| uris)
+}
+
+fn pick_uris_impl(p: &FileDialog<'_>, multiple: bool) -> Option<(Vec<String>, FileSelection)> {
 	let conn = Connection::new_session().ok()?;
 	let proxy = conn.with_proxy(DESKTOP_BUS_NAME, DESKTOP_PATH, time::Duration::from_secs(30));
 
@@ -45,21 +77,19 @@ fn pick_files_impl(p: &FileDialog<'_>, multiple: bool) -> Option<Vec<PathBuf>> {
 		return None;
 	}
 	let uris = result_uris(&results)?;
+	Some((uris, result_selection(p, &results)))
+}
 
-	let paths = uris
-		.into_iter()
-		.filter_map(|uri| parse_file_uri(&uri))
-		.collect::<Vec<_>>();
+pub fn save_file(p: &FileDialog<'_>) -> Option<(PathBuf, FileSelection)> {
+	let (uri, selection) = save_uri_impl(p)?;
+	Some((parse_file_uri(&uri)?, selection))
+}
 
-	if paths.is_empty() {
-		None
-	}
-	else {
-		Some(paths)
-	}
+pub fn save_uri(p: &FileDialog<'_>) -> Option<String> {
+	save_uri_impl(p).map(|(uri, _)| uri)
 }
 
-pub fn save_file(p: &FileDialog<'_>) -> Option<PathBuf> {
+fn save_uri_impl(p: &FileDialog<'_>) -> Option<(String, FileSelection)> {
 	let conn = Connection::new_session().ok()?;
 	let proxy = conn.with_proxy(DESKTOP_BUS_NAME, DESKTOP_PATH, time::Duration::from_secs(30));
 
@@ -73,8 +103,47 @@ pub fn save_file(p: &FileDialog<'_>) -> Option<PathBuf> {
 	if response != 0 {
 		return None;
 	}
+	let uri = result_uris(&results)?.into_iter().next()?;
+	Some((uri, result_selection(p, &results)))
+}
+
+pub fn save_files(p: &FileDialog<'_>, names: &[&str]) -> Option<Vec<PathBuf>> {
+	if !utils::valid_file_names(names) {
+		return None;
+	}
+	let conn = Connection::new_session().ok()?;
+	let proxy = conn.with_proxy(DESKTOP_BUS_NAME, DESKTOP_PATH, time::Duration::from_secs(30));
+
+	let mut options: PropMap = PropMap::new();
+	if let Some(accept_label) = p.accept_label {
+		options.insert(String::from("accept_label"), Variant(Box::new(accept_label.to_string())));
+	}
+	if let Some(path) = utils::abspath(p.path) {
+		let directory = if path.is_dir() { Some(path.as_ref()) } else { path.parent() };
+		if let Some(folder) = directory.and_then(portal_directory_bytes) {
+			options.insert(String::from("current_folder"), Variant(Box::new(folder)));
+		}
+	}
+	// The file names are null-terminated byte strings.
+	let files = names.iter().map(|name| [name.as_bytes(), b"\0"].concat()).collect::<Vec<_>>();
+	options.insert(String::from("files"), Variant(Box::new(files)));
+
+	let (request_path,): (dbus::Path<'static>,) = proxy
+		.method_call(FILE_CHOOSER_INTERFACE, "SaveFiles", (String::new(), p.title, options))
+		.ok()?;
+
+	let (response, results) = wait_portal_response(&conn, request_path, time::Duration::from_secs(120))?;
+	if response != 0 {
+		return None;
+	}
+	// The portal returns one URI per proposed name, in the same order.
 	let uris = result_uris(&results)?;
-	uris.into_iter().find_map(|uri| parse_file_uri(&uri))
+	if uris.len() != names.len() {
+		return None;
+	}
+	let paths = uris.iter().map(|uri| parse_file_uri(uri)).collect::<Option<Vec<_>>>()?;
+	// The portal only picks the folder, it does not ask before replacing files.
+	utils::confirm_overwrite_files(p, &paths).then_some(paths)
 }
 
 pub fn choose_folder(p: &FileDialog<'_>) -> Option<PathBuf> {
@@ -92,6 +161,9 @@ fn choose_folders_impl(p: &FileDialog<'_>, multiple: bool) -> Option<Vec<PathBuf
 	let mut options: PropMap = PropMap::new();
 	options.insert(String::from("directory"), Variant(Box::new(true)));
 	options.insert(String::from("multiple"), Variant(Box::new(multiple)));
+	if let Some(accept_label) = p.accept_label {
+		options.insert(String::from("accept_label"), Variant(Box::new(accept_label.to_string())));
+	}
 	if let Some(directory) = p.path {
 		if let Some(folder) = portal_directory_bytes(directory) {
 			options.insert(String::from("current_folder"), Variant(Box::new(folder)));
@@ -114,6 +186,10 @@ fn choose_folders_impl(p: &FileDialog<'_>, multiple: bool) -> Option<Vec<PathBuf
 
 fn portal_file_options(p: &FileDialog<'_>) -> PropMap {
 	let mut options: PropMap = PropMap::new();
+	// The portal has no cancel label option.
+	if let Some(accept_label) = p.accept_label {
+		options.insert(String::from("accept_label"), Variant(Box::new(accept_label.to_string())));
+	}
 	if let Some(path) = utils::abspath(p.path) {
 		if path.is_dir() {
 			if let Some(folder) = portal_directory_bytes(&path) {
@@ -131,30 +207,36 @@ fn portal_file_options(p: &FileDialog<'_>) -> PropMap {
 			}
 		}
 	}
-	if let Some(filters) = p.filters {
-		let filters = portal_filters(filters);
-		if let Some(current_filter) = filters.first() {
-			options.insert(String::from("current_filter"), Variant(Box::new(current_filter.clone())));
-		}
+	if let Some(default) = utils::default_filter(p) {
+		let filters = utils::dialog_filters(p).iter().map(portal_filter).collect::<Vec<_>>();
+		options.insert(String::from("current_filter"), Variant(Box::new(filters[default].clone())));
 		options.insert(String::from("filters"), Variant(Box::new(filters)));
 	}
+	if !p.choices.is_empty() {
+		let choices = p.choices.iter().map(portal_choice).collect::<Vec<_>>();
+		options.insert(String::from("choices"), Variant(Box::new(choices)));
+	}
 	options
 }
 
 type PortalFilter = (String, Vec<(u32, String)>);
 
-fn portal_filters(filters: &[FileFilter<'_>]) -> Vec<PortalFilter> {
-	let mut result = Vec::with_capacity(filters.len() + 1);
-	for filter in filters {
-		result.push(portal_filter(filter));
-	}
-	result.push(portal_filter(&FileFilter::ALL_FILES));
-	result
+// The choice id, label, options as `(id, label)` pairs and the default option.
+type PortalChoice = (String, String, Vec<(String, String)>, String);
+
+fn portal_choice(choice: &FileChoice<'_>) -> PortalChoice {
+	let options = choice.options.iter()
+		.map(|&(id, label)| (id.to_string(), label.to_string()))
+		.collect::<Vec<_>>();
+
+	(choice.id.to_string(), choice.label.to_string(), options, choice.default.to_string())
 }
 
 fn portal_filter(filter: &FileFilter) -> PortalFilter {
-	let patterns = filter.patterns.iter()
-		.map(|&pattern| (0u32, pattern.to_string()))
+	// The filter type is 0 for glob patterns and 1 for MIME types.
+	let patterns = utils::glob_patterns(filter.patterns.iter().copied(), utils::GlobSyntax::CaseSensitive).into_iter()
+		.map(|pattern| (0u32, pattern))
+		.chain(filter.mime_types.iter().map(|&mime_type| (1u32, mime_type.to_string())))
 		.collect::<Vec<_>>();
 
 	(filter.name.to_string(), patterns)
@@ -209,6 +291,30 @@ fn wait_portal_response(
 	}
 }
 
+// The selected choices are returned as `(id, option)` pairs, choices missing from the results keep their default.
+fn result_selection(p: &FileDialog<'_>, results: &HashMap<String, Variant<Box<dyn RefArg + 'static>>>) -> FileSelection {
+	let mut selection = utils::file_selection(p, result_filter(p, results));
+	let Some(choices) = results.get("choices").and_then(|choices| choices.0.as_iter()) else {
+		return selection;
+	};
+	for choice in choices {
+		let Some(mut fields) = choice.as_iter() else { continue };
+		let (Some(id), Some(option)) = (fields.next().and_then(|id| id.as_str()), fields.next().and_then(|option| option.as_str())) else { continue };
+		if let Some(entry) = selection.choices.iter_mut().find(|(choice, _)| choice == id) {
+			entry.1 = option.to_string();
+		}
+	}
+	selection
+}
+
+// The portal returns the selected filter itself, find it by name.
+fn result_filter(p: &FileDialog<'_>, results: &HashMap<String, Variant<Box<dyn RefArg + 'static>>>) -> Option<usize> {
+	let current_filter = results.get("current_filter")?;
+	let name = current_filter.0.as_iter()?.next()?.as_str()?;
+	let index = utils::dialog_filters(p).iter().position(|filter| filter.name == name)?;
+	utils::filter_index(p, index)
+}
+
 fn result_uris(results: &HashMap<String, Variant<Box<dyn RefArg + 'static>>>) -> Option<Vec<String>> {
 	let uris = results.get("uris")?;
 	let values = uris.0.as_iter()?;
@@ -268,10 +374,202 @@ pub fn text_input(_: &TextInput<'_>) -> Option<String> {
 	None
 }
 
-pub fn color_picker(_: &ColorPicker<'_>) -> Option<ColorValue> {
+pub fn text_viewer(_: &TextViewer<'_>) -> bool {
+	false
+}
+
+pub fn login_dialog(_: &LoginDialog<'_>) -> Option<Credentials> {
 	None
 }
 
+pub fn about_dialog(_: &AboutDialog<'_>) {
+}
+
+// The portal has no color dialog, the color is picked from the screen instead.
+pub fn color_picker(p: &ColorPicker<'_>) -> Option<ColorValue> {
+	let conn = Connection::new_session().ok()?;
+	let proxy = conn.with_proxy(DESKTOP_BUS_NAME, DESKTOP_PATH, time::Duration::from_secs(30));
+
+	let (request_path,): (dbus::Path<'static>,) = proxy
+		.method_call(SCREENSHOT_INTERFACE, "PickColor", (String::new(), PropMap::new()))
+		.ok()?;
+
+	let (response, results) = wait_portal_response(&conn, request_path, time::Duration::from_secs(120))?;
+	if response != 0 {
+		return None;
+	}
+
+	// The color is a `(ddd)` struct of red, green and blue in the range 0.0 to 1.0.
+	let mut components = results.get("color")?.0.as_iter()?.map(|value| value.as_f64());
+	let mut component = || Some((components.next()??.clamp(0.0, 1.0) * 255.0).round() as u8);
+	Some(ColorValue {
+		red: component()?,
+		green: component()?,
+		blue: component()?,
+		alpha: if p.alpha { p.value.alpha } else { 255 },
+	})
+}
+
+pub fn font_picker(_: &FontPicker<'_>) -> Option<FontValue> {
+	None
+}
+
+pub fn print_dialog(p: &PrintDialog<'_>) -> Option<PrintSettings> {
+	let conn = Connection::new_session().ok()?;
+	let proxy = conn.with_proxy(DESKTOP_BUS_NAME, DESKTOP_PATH, time::Duration::from_secs(30));
+
+	let (request_path,): (dbus::Path<'static>,) = proxy
+		.method_call(PRINT_INTERFACE, "PreparePrint", (String::new(), p.title, PropMap::new(), PropMap::new(), PropMap::new()))
+		.ok()?;
+
+	let (response, results) = wait_portal_response(&conn, request_path, time::Duration::from_secs(600))?;
+	if response != 0 {
+		return None;
+	}
+
+	let settings = results.get("settings").map(|value| &*value.0 as &dyn RefArg);
+	let page_setup = results.get("page-setup").map(|value| &*value.0 as &dyn RefArg);
+	let setting = |key: &str| settings.and_then(|dict| dict_get(dict, key)).and_then(|value| value.as_str());
+	let page = |key: &str| page_setup.and_then(|dict| dict_get(dict, key));
+
+	let page_ranges = match setting("print-pages") {
+		Some("ranges") => setting("page-ranges").map(parse_page_ranges).unwrap_or_default(),
+		_ => Vec::new(),
+	};
+
+	let orientation = match page("Orientation").and_then(|value| value.as_str()).or_else(|| setting("orientation")) {
+		Some("landscape") => PageOrientation::Landscape,
+		Some("reverse_portrait") => PageOrientation::ReversePortrait,
+		Some("reverse_landscape") => PageOrientation::ReverseLandscape,
+		_ => PageOrientation::Portrait,
+	};
+
+	Some(PrintSettings {
+		printer: setting("printer").unwrap_or_default().to_string(),
+		copies: setting("n-copies").and_then(|value| value.parse().ok()).unwrap_or(1).max(1),
+		page_ranges,
+		paper: page("Name").and_then(|value| value.as_str()).unwrap_or_default().to_string(),
+		paper_width: page("Width").and_then(|value| value.as_f64()).unwrap_or(0.0),
+		paper_height: page("Height").and_then(|value| value.as_f64()).unwrap_or(0.0),
+		orientation,
+		token: results.get("token").and_then(|value| value.0.as_u64()).map(|token| token as u32),
+	})
+}
+
+pub fn print_file(token: u32, title: &str, path: &Path) -> bool {
+	let Ok(file) = fs::File::open(path) else { return false };
+	let Ok(conn) = Connection::new_session() else { return false };
+	let proxy = conn.with_proxy(DESKTOP_BUS_NAME, DESKTOP_PATH, time::Duration::from_secs(30));
+
+	// Safety: the descriptor was just released from `file` and is owned by nothing else.
+	let fd = unsafe { OwnedFd::new(file.into_raw_fd()) };
+	let mut options: PropMap = PropMap::new();
+	options.insert(String::from("token"), Variant(Box::new(token)));
+
+	let result: Result<(dbus::Path<'static>,), _> = proxy
+		.method_call(PRINT_INTERFACE, "Print", (String::new(), title, fd, options));
+	let Ok((request_path,)) = result else { return false };
+
+	matches!(wait_portal_response(&conn, request_path, time::Duration::from_secs(120)), Some((0, _)))
+}
+
+pub fn icon_picker(p: &IconPicker<'_>) -> Option<IconValue> {
+	// No themed icons through the portal, ask for an image file instead.
+	utils::pick_icon_file(p.title, p.owner)
+}
+
+pub fn app_chooser(_: &AppChooser<'_>) -> Option<String> {
+	// The portal launches the chosen application itself and does not tell which one was chosen.
+	None
+}
+
+pub fn app_chooser_open(p: &AppChooser<'_>) -> bool {
+	let Ok(file) = fs::File::open(p.path) else { return false };
+	let Ok(conn) = Connection::new_session() else { return false };
+	let proxy = conn.with_proxy(DESKTOP_BUS_NAME, DESKTOP_PATH, time::Duration::from_secs(30));
+
+	// Safety: the descriptor was just released from `file` and is owned by nothing else.
+	let fd = unsafe { OwnedFd::new(file.into_raw_fd()) };
+	let mut options: PropMap = PropMap::new();
+	options.insert(String::from("ask"), Variant(Box::new(true)));
+
+	let result: Result<(dbus::Path<'static>,), _> = proxy
+		.method_call(OPEN_URI_INTERFACE, "OpenFile", (String::new(), fd, options));
+	let Ok((request_path,)) = result else { return false };
+
+	matches!(wait_portal_response(&conn, request_path, time::Duration::from_secs(600)), Some((0, _)))
+}
+
+// Returns `None` if the settings portal is not available.
+pub fn appearance() -> Option<Appearance> {
+	let conn = Connection::new_session().ok()?;
+	let proxy = conn.with_proxy(DESKTOP_BUS_NAME, DESKTOP_PATH, time::Duration::from_secs(5));
+
+	let color_scheme = read_setting(&proxy, "color-scheme")?;
+	let color_scheme = match color_scheme.as_u64() {
+		Some(1) => ColorScheme::Dark,
+		Some(2) => ColorScheme::Light,
+		_ => ColorScheme::NoPreference,
+	};
+
+	// The accent color is a `(ddd)` struct, out of range components mean no accent color is set.
+	let accent_color = read_setting(&proxy, "accent-color").and_then(|accent| {
+		let components = unwrap_variant(&*accent).as_iter()?.map(|value| value.as_f64()).collect::<Option<Vec<_>>>()?;
+		let &[red, green, blue] = components.as_slice() else { return None };
+		let component = |value: f64| (0.0..=1.0).contains(&value).then(|| (value * 255.0).round() as u8);
+		Some(ColorValue::rgb(component(red)?, component(green)?, component(blue)?))
+	});
+
+	let high_contrast = read_setting(&proxy, "contrast").and_then(|contrast| contrast.as_u64()) == Some(1);
+
+	Some(Appearance { color_scheme, accent_color, high_contrast })
+}
+
+fn read_setting(proxy: &dbus::blocking::Proxy<'_, &Connection>, key: &str) -> Option<Box<dyn RefArg>> {
+	let result: Result<(Variant<Box<dyn RefArg>>,), _> = proxy
+		.method_call(SETTINGS_INTERFACE, "ReadOne", (APPEARANCE_NAMESPACE, key));
+	let result = match result {
+		Ok(result) => result,
+		// Older portals only have the deprecated `Read`, which wraps the value in another variant.
+		Err(_) => proxy.method_call(SETTINGS_INTERFACE, "Read", (APPEARANCE_NAMESPACE, key)).ok()?,
+	};
+	Some(result.0.0)
+}
+
+fn unwrap_variant(mut value: &dyn RefArg) -> &dyn RefArg {
+	while value.arg_type() == ArgType::Variant {
+		match value.as_iter().and_then(|mut iter| iter.next()) {
+			Some(inner) => value = inner,
+			None => break,
+		}
+	}
+	value
+}
+
+// Looks up a key in an `a{sv}` dictionary, whose iterator alternates between keys and values.
+fn dict_get<'a>(dict: &'a dyn RefArg, key: &str) -> Option<&'a dyn RefArg> {
+	let mut iter = dict.as_iter()?;
+	while let Some(name) = iter.next() {
+		let value = iter.next()?;
+		if name.as_str() == Some(key) {
+			return Some(value);
+		}
+	}
+	None
+}
+
+// GTK stores page ranges zero-based, eg. "0-2,4".
+fn parse_page_ranges(ranges: &str) -> Vec<(u32, u32)> {
+	ranges.split(',')
+		.filter_map(|range| {
+			let (first, last) = range.split_once('-').unwrap_or((range, range));
+			let first = first.trim().parse::<u32>().ok()?;
+			let last = last.trim().parse::<u32>().ok()?;
+			Some((first + 1, last.max(first) + 1))
+		})
+		.collect()
+}
+
 pub fn notify(p: &Notification<'_>) {
 	let conn = match Connection::new_session() {
 		Ok(conn) => conn,
@@ -70,12 +70,17 @@ pub fn message_box(p: &MessageBox<'_>) -> Option<MessageResult> {
 
 
 
-pub fn pick_file(p: &FileDialog<'_>) -> Option<PathBuf> {
-	pick_files_impl(p, false).and_then(|paths| paths.into_iter().next())
+pub fn pick_file(p: &FileDialog<'_>) -> Option<(PathBuf, FileSelection)> {
+	let path = pick_files_impl(p, false)?.into_iter().next()?;
+	// Zenity does not report the selected filter, guess it from the file name.
+	let filter = utils::guess_filter(p, &path, filter_patterns);
+	Some((path, utils::file_selection(p, filter)))
 }
 
-pub fn pick_files(p: &FileDialog<'_>) -> Option<Vec<PathBuf>> {
-	pick_files_impl(p, true)
+pub fn pick_files(p: &FileDialog<'_>) -> Option<(Vec<PathBuf>, FileSelection)> {
+	let paths = pick_files_impl(p, true)?;
+	let filter = paths.first().and_then(|path| utils::guess_filter(p, path, filter_patterns));
+	Some((paths, utils::file_selection(p, filter)))
 }
 
 fn pick_files_impl(p: &FileDialog<'_>, multiple: bool) -> Option<Vec<PathBuf>> {
@@ -85,10 +90,10 @@ fn pick_files_impl(p: &FileDialog<'_>, multiple: bool) -> Option<Vec<PathBuf>> {
 		os(p.title),
 	];
 
-	let file_path = utils::abspath(p.path);
+	let file_path = filename_arg(p.path);
 	if let Some(file_path) = &file_path {
 		args.push(os("--filename"));
-		args.push(file_path.as_os_str());
+		args.push(file_path);
 	}
 
 	if multiple {
@@ -97,11 +102,12 @@ fn pick_files_impl(p: &FileDialog<'_>, multiple: bool) -> Option<Vec<PathBuf>> {
 		args.push(os("\n"));
 	}
 
-	let filters = filter_strings(p.filters);
+	let filters = filter_strings(p);
 	for filter in &filters {
 		args.push(os("--file-filter"));
 		args.push(os(filter));
 	}
+	push_labels(&mut args, p);
 
 	let (code, output) = invoke_output_bytes("zenity", &args);
 	if code != Some(0) {
@@ -114,36 +120,41 @@ fn pick_files_impl(p: &FileDialog<'_>, multiple: bool) -> Option<Vec<PathBuf>> {
 		.collect::<Vec<_>>())
 }
 
-pub fn save_file(p: &FileDialog<'_>) -> Option<PathBuf> {
+pub fn save_file(p: &FileDialog<'_>) -> Option<(PathBuf, FileSelection)> {
 	let mut args = vec![
 		os("--file-selection"),
 		os("--save"),
-		os("--confirm-overwrite"),
 		os("--title"),
 		os(p.title),
 	];
+	if p.confirm_overwrite {
+		args.push(os("--confirm-overwrite"));
+	}
 
-	let file_path = utils::abspath(p.path);
+	let file_path = filename_arg(p.path);
 	if let Some(file_path) = &file_path {
 		args.push(os("--filename"));
-		args.push(file_path.as_os_str());
+		args.push(file_path);
 	}
 
-	let filters = filter_strings(p.filters);
+	let filters = filter_strings(p);
 	for filter in &filters {
 		args.push(os("--file-filter"));
 		args.push(os(filter));
 	}
+	push_labels(&mut args, p);
 
 	let (code, output) = invoke_output_bytes("zenity", &args);
 	if code != Some(0) {
 		return None;
 	}
 
-	output
+	let path = output
 		.split(|&byte| byte == b'\n')
 		.find(|line| !line.is_empty())
-		.map(|line| PathBuf::from(OsStr::from_bytes(line)))
+		.map(|line| PathBuf::from(OsStr::from_bytes(line)))?;
+	let filter = utils::guess_filter(p, &path, filter_patterns);
+	Some((path, utils::file_selection(p, filter)))
 }
 
 pub fn choose_folder(p: &FileDialog<'_>) -> Option<PathBuf> {
@@ -155,22 +166,24 @@ pub fn choose_folders(p: &FileDialog<'_>) -> Option<Vec<PathBuf>> {
 }
 
 fn choose_folders_impl(p: &FileDialog<'_>, multiple: bool) -> Option<Vec<PathBuf>> {
-	let directory = p.path.unwrap_or_else(|| Path::new("."));
-	let args = [
+	let directory = filename_arg(Some(p.path.unwrap_or_else(|| Path::new("."))));
+	let mut args = vec![
 		os("--file-selection"),
 		os("--directory"),
 		os("--title"),
 		os(p.title),
-		os("--filename"),
-		directory.as_os_str(),
-
-		os("--multiple"),
-		os("--separator"),
-		os("\n"),
 	];
+	if let Some(directory) = &directory {
+		args.push(os("--filename"));
+		args.push(directory);
+	}
 
-	let args = if multiple { &args[..] }
-	else { &args[..args.len() - 3] };
+	if multiple {
+		args.push(os("--multiple"));
+		args.push(os("--separator"));
+		args.push(os("\n"));
+	}
+	push_labels(&mut args, p);
 
 	let (code, output) = invoke_output_bytes("zenity", &args);
 	if code != Some(0) {
@@ -184,19 +197,39 @@ fn choose_folders_impl(p: &FileDialog<'_>, multiple: bool) -> Option<Vec<PathBuf
 		.collect())
 }
 
-fn filter_strings(filters: Option<&[FileFilter<'_>]>) -> Vec<String> {
-	let mut result = Vec::new();
-	if let Some(filters) = filters {
-		for filter in filters {
-			result.push(filter_string(filter));
-		}
-		result.push(filter_string(&FileFilter::ALL_FILES));
+// Zenity treats a path without a trailing `/` as a file: it opens the parent folder and proposes the name.
+fn filename_arg(path: Option<&Path>) -> Option<std::ffi::OsString> {
+	let path = utils::abspath(path)?;
+	let mut arg = path.as_os_str().to_os_string();
+	if path.is_dir() && !arg.as_bytes().ends_with(b"/") {
+		arg.push("/");
 	}
-	result
+	Some(arg)
+}
+
+// Zenity matches patterns case-sensitively.
+fn filter_patterns(filter: &FileFilter<'_>) -> Vec<String> {
+	mime::filter_patterns(filter, utils::GlobSyntax::SpaceSeparated)
+}
+
+fn push_labels<'a>(args: &mut Vec<&'a OsStr>, p: &FileDialog<'a>) {
+	if let Some(accept_label) = p.accept_label {
+		args.push(os("--ok-label"));
+		args.push(os(accept_label));
+	}
+	if let Some(cancel_label) = p.cancel_label {
+		args.push(os("--cancel-label"));
+		args.push(os(cancel_label));
+	}
+}
+
+// Zenity always selects the first filter, so the default filter is moved to the front.
+fn filter_strings(p: &FileDialog<'_>) -> Vec<String> {
+	utils::default_first_filters(p).iter().map(filter_string).collect()
 }
 
 fn filter_string(filter: &FileFilter) -> String {
-	format!("{} | {}", filter.name, utils::PrintJoin { parts: filter.patterns, separator: " " })
+	format!("{} | {}", filter.name, filter_patterns(filter).join(" "))
 }
 
 
@@ -254,69 +287,316 @@ fn text_input_password(p: &TextInput<'_>) -> Option<String> {
 	if status == Some(0) { Some(output) } else { None }
 }
 
-fn temp_file_path(prefix: &str) -> PathBuf {
-	let nanos = std::time::SystemTime::now()
-		.duration_since(std::time::UNIX_EPOCH)
-		.map_or(0, |d| d.as_nanos());
-	env::temp_dir().join(format!("{prefix}-{}-{nanos}.txt", process::id()))
+pub fn text_viewer(p: &TextViewer<'_>) -> bool {
+	let temp_path;
+	let file_path = match p.content {
+		TextContent::File(path) => path,
+		TextContent::Text(text) => {
+			temp_path = temp_file_path("rustydialogs-zenity-viewer");
+			if std::fs::write(&temp_path, text.as_bytes()).is_err() {
+				return false;
+			}
+			&temp_path
+		}
+	};
+
+	let mut args = vec![
+		os("--text-info"),
+		os("--title"),
+		os(p.title),
+		os("--filename"),
+		file_path.as_os_str(),
+		os("--width=640"),
+		os("--height=480"),
+	];
+
+	if let Some(checkbox) = p.checkbox {
+		args.push(os("--checkbox"));
+		args.push(os(checkbox));
+	}
+
+	let status = invoke("zenity", &args);
+	if let TextContent::Text(_) = p.content {
+		let _ = std::fs::remove_file(file_path);
+	}
+	status == Some(0)
 }
 
+pub fn about_dialog(p: &AboutDialog<'_>) {
+	let title = format!("About {}", p.name);
+	let text = about_markup(p);
+
+	loop {
+		let mut args = vec![
+			os("--info"),
+			os("--title"),
+			os(&title),
+			os("--text"),
+			os(&text),
+			os("--ok-label"),
+			os("Close"),
+		];
+		if !p.icon.is_empty() {
+			args.push(os("--icon"));
+			args.push(os(p.icon));
+		}
+		if !p.license.is_empty() {
+			args.push(os("--extra-button"));
+			args.push(os("License"));
+		}
 
+		let (status, output) = invoke_output("zenity", &args);
+		if !(status == Some(1) && output == "License") {
+			return;
+		}
 
-pub fn color_picker(p: &ColorPicker<'_>) -> Option<ColorValue> {
-	let value = format_color(p.value);
+		text_viewer(&TextViewer {
+			title: "License",
+			content: TextContent::Text(p.license),
+			checkbox: None,
+			owner: p.owner,
+		});
+	}
+}
+
+fn about_markup(p: &AboutDialog<'_>) -> String {
+	let mut text = format!("<big><b>{} {}</b></big>", markup_escape(p.name), markup_escape(p.version));
+	if !p.comments.is_empty() {
+		text.push_str("\n\n");
+		text.push_str(&markup_escape(p.comments));
+	}
+	if !p.website.is_empty() {
+		let website = markup_escape(p.website);
+		text.push_str(&format!("\n\n<a href=\"{website}\">{website}</a>"));
+	}
+	if !p.authors.is_empty() {
+		text.push_str("\n\nAuthors:");
+		for author in p.authors {
+			text.push('\n');
+			text.push_str(&markup_escape(author));
+		}
+	}
+	text
+}
+
+fn markup_escape(value: &str) -> String {
+	let mut result = String::with_capacity(value.len());
+	for ch in value.chars() {
+		match ch {
+			'&' => result.push_str("&amp;"),
+			'<' => result.push_str("&lt;"),
+			'>' => result.push_str("&gt;"),
+			'"' => result.push_str("&quot;"),
+			'\'' => result.push_str("&apos;"),
+			_ => result.push(ch),
+		}
+	}
+	result
+}
+
+pub fn login_dialog(p: &LoginDialog<'_>) -> Option<Credentials> {
+	// Zenity prints `username|password` to stdout, the password never appears in argv.
 	let args = [
-		os("--color-selection"),
+		os("--password"),
+		os("--username"),
 		os("--title"),
 		os(p.title),
-		os("--color"),
-		os(&value),
+		os("--text"),
+		os(p.message),
 	];
 	let (status, output) = invoke_output("zenity", &args);
+	if status != Some(0) {
+		return None;
+	}
+
+	let (username, password) = output.split_once('|')?;
+	Some(Credentials {
+		username: username.to_string(),
+		password: password.to_string(),
+	})
+}
+
 
+
+pub fn print_dialog(_: &PrintDialog<'_>) -> Option<PrintSettings> {
+	// Zenity has no print dialog.
+	None
+}
+
+pub fn app_chooser(p: &AppChooser<'_>) -> Option<String> {
+	let entries = app_chooser_entries(p);
+	let file_name = p.path.file_name().unwrap_or(p.path.as_os_str()).to_string_lossy();
+	let text = format!("Open <b>{}</b> with:", markup_escape(&file_name));
+	let mut args = vec![
+		os("--list"),
+		os("--title"),
+		os(p.title),
+		os("--text"),
+		os(&text),
+		os("--column=Application"),
+		os("--column=Id"),
+		os("--hide-column=2"),
+		os("--print-column=2"),
+	];
+	for entry in &entries {
+		args.push(os(&entry.name));
+		args.push(os(&entry.id));
+	}
+
+	let (status, output) = invoke_output("zenity", &args);
 	if status != Some(0) {
 		return None;
 	}
+	// Some zenity versions print the column twice when a row is double clicked.
+	output.split('|').next().filter(|id| !id.is_empty()).map(String::from)
+}
 
-	let color = parse_color(&output)
-		.unwrap_or_else(|| panic!("zenity color_picker returned invalid color: {output}"));
-	Some(color)
+pub fn icon_picker(p: &IconPicker<'_>) -> Option<IconValue> {
+	// Zenity has no icon chooser, list the themed icon names instead.
+	let names = icons::icon_names(p.context, &icons::desktop_icon_theme());
+	let mut args = vec![
+		os("--list"),
+		os("--title"),
+		os(p.title),
+		os("--text"),
+		os("Choose an icon:"),
+		os("--column=Icon"),
+		os("--height=480"),
+		os("--extra-button"),
+		os("Browse"),
+	];
+	for name in &names {
+		args.push(os(name));
+	}
+
+	let (status, output) = invoke_output("zenity", &args);
+	match status {
+		Some(0) => output.split('|').next().filter(|name| !name.is_empty()).map(|name| IconValue::Name(name.to_string())),
+		Some(1) if output == "Browse" => utils::pick_icon_file(p.title, p.owner),
+		_ => None,
+	}
 }
 
-fn format_color(color: ColorValue) -> String {
-	format!("#{:02X}{:02X}{:02X}", color.red, color.green, color.blue)
+pub struct LogWindowImpl {
+	child: process::Child,
+	stdin: Option<process::ChildStdin>,
 }
 
-fn parse_color(value: &str) -> Option<ColorValue> {
-	let value = value.trim();
+impl LogWindowImpl {
+	pub fn new(title: &str) -> LogWindowImpl {
+		let mut child = command("zenity")
+			.args([os("--text-info"), os("--auto-scroll"), os("--title"), os(title), os("--width=640"), os("--height=480")])
+			.stdin(process::Stdio::piped())
+			.stdout(process::Stdio::null())
+			.spawn()
+			.expect("failed to spawn process");
+		let stdin = child.stdin.take();
+		LogWindowImpl { child, stdin }
+	}
 
-	if let Some(value) = value.strip_prefix("rgb(").and_then(|v| v.strip_suffix(')')) {
-		let mut parts = value.split(',').map(str::trim);
-		let red = parts.next()?.parse::<u8>().ok()?;
-		let green = parts.next()?.parse::<u8>().ok()?;
-		let blue = parts.next()?.parse::<u8>().ok()?;
-		if parts.next().is_some() {
-			return None;
+	pub fn append_line(&mut self, line: &str) {
+		use std::io::Write as _;
+		if let Some(stdin) = &mut self.stdin {
+			// The user may have closed the window already, ignore broken pipes.
+			_ = writeln!(stdin, "{line}");
 		}
-		return Some(ColorValue { red, green, blue });
 	}
 
-	let value = value.strip_prefix('#').unwrap_or(value);
-	if value.len() != 6 && value.len() != 8 {
-		return None;
+	pub fn set_title(&mut self, _title: &str) {
+		// Zenity cannot change the title of a running dialog.
+	}
+
+	pub fn finish(&mut self) {
+		// Closing stdin tells zenity the log is complete.
+		self.stdin = None;
+	}
+
+	pub fn wait_closed(mut self) {
+		self.stdin = None;
+		_ = self.child.wait();
 	}
-	if !value.is_ascii() {
+}
+
+impl Drop for LogWindowImpl {
+	fn drop(&mut self) {
+		if let Ok(None) = self.child.try_wait() {
+			_ = self.child.kill();
+			_ = self.child.wait();
+		}
+	}
+}
+
+pub fn color_picker(p: &ColorPicker<'_>) -> Option<ColorValue> {
+	// GTK 3 does not understand `#RRGGBBAA`, pass the color in functional notation.
+	let alpha = if p.alpha { p.value.alpha } else { 255 };
+	let value = format!("{:#}", ColorValue { alpha, ..p.value });
+	let mut args = vec![
+		os("--color-selection"),
+		os("--title"),
+		os(p.title),
+		os("--color"),
+		os(&value),
+	];
+	// Zenity cannot be given custom colors, show its default palette instead.
+	if !p.palette.is_empty() || p.key.is_some() {
+		args.push(os("--show-palette"));
+	}
+	let (status, output) = invoke_output("zenity", &args);
+
+	if status != Some(0) {
 		return None;
 	}
 
-	let red = u8::from_str_radix(&value[0..2], 16).ok()?;
-	let green = u8::from_str_radix(&value[2..4], 16).ok()?;
-	let blue = u8::from_str_radix(&value[4..6], 16).ok()?;
-	if value.len() == 8 {
-		u8::from_str_radix(&value[6..8], 16).ok()?;
+	let mut color = output.parse::<ColorValue>().ok()
+		.unwrap_or_else(|| panic!("zenity color_picker returned invalid color: {output}"));
+	// Zenity always shows the alpha slider, ignore it when alpha was not asked for
+	if !p.alpha {
+		color.alpha = 255;
+	}
+	Some(color)
+}
+
+pub fn font_picker(p: &FontPicker<'_>) -> Option<FontValue> {
+	// Zenity has no font chooser, ask for the font properties in a form instead.
+	// Form fields cannot be prefilled, empty fields keep the initial value.
+	let mut font = utils::parse_font(p.value);
+	let text = format!("Current font: {}", font);
+	let args = [
+		os("--forms"),
+		os("--title"),
+		os(p.title),
+		os("--text"),
+		os(&text),
+		os("--separator=|"),
+		os("--add-entry=Family"),
+		os("--add-combo=Style"),
+		os("--combo-values=Normal|Italic|Oblique"),
+		os("--add-combo=Weight"),
+		os("--combo-values=Thin|Light|Regular|Medium|Semi-Bold|Bold|Heavy"),
+		os("--add-entry=Size"),
+	];
+	let (status, output) = invoke_output("zenity", &args);
+	if status != Some(0) {
+		return None;
 	}
 
-	Some(ColorValue { red, green, blue })
+	let mut fields = output.split('|').map(str::trim);
+	if let Some(family) = fields.next().filter(|family| !family.is_empty()) {
+		font.family = family.to_string();
+	}
+	match fields.next() {
+		Some("Normal") => font.style = FontStyle::Normal,
+		Some("Italic") => font.style = FontStyle::Italic,
+		Some("Oblique") => font.style = FontStyle::Oblique,
+		_ => {}
+	}
+	if let Some(weight) = fields.next().and_then(utils::font_weight) {
+		font.weight = weight;
+	}
+	if let Some(size) = fields.next().and_then(|size| size.parse::<f32>().ok()).filter(|&size| size > 0.0) {
+		font.size = size;
+	}
+	Some(font)
 }
 
 
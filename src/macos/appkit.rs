@@ -118,8 +118,12 @@ fn filter_types(filters: Option<&[FileFilter<'_>]>) -> Option<Vec<String>> {
 	let mut result = Vec::new();
 
 	for filter in filters {
-		for pattern in filter.patterns {
-			let Some(file_type) = pattern_to_file_type(pattern) else {
+		// MIME types cannot be translated without the UniformTypeIdentifiers framework, allow all files instead.
+		if !filter.mime_types.is_empty() {
+			return None;
+		}
+		for pattern in utils::glob_patterns(filter.patterns.iter().copied(), utils::GlobSyntax::CaseInsensitive) {
+			let Some(file_type) = pattern_to_file_type(&pattern) else {
 				return None;
 			};
 			if !result.iter().any(|item| item == &file_type) {
@@ -205,18 +209,25 @@ pub fn message_box(p: &MessageBox<'_>) -> Option<MessageResult> {
 	})
 }
 
-pub fn pick_file(p: &FileDialog<'_>) -> Option<PathBuf> {
-	run_open_panel(p, false).and_then(|paths| paths.into_iter().next())
+// The panel merges all filters into its allowed content types, the selected filter is unknown.
+// Choices are not supported, they keep their default values.
+pub fn pick_file(p: &FileDialog<'_>) -> Option<(PathBuf, FileSelection)> {
+	let path = run_open_panel(p, false)?.into_iter().next()?;
+	Some((path, utils::file_selection(p, None)))
 }
 
-pub fn pick_files(p: &FileDialog<'_>) -> Option<Vec<PathBuf>> {
-	run_open_panel(p, true)
+pub fn pick_files(p: &FileDialog<'_>) -> Option<(Vec<PathBuf>, FileSelection)> {
+	run_open_panel(p, true).map(|paths| (paths, utils::file_selection(p, None)))
 }
 
-pub fn save_file(p: &FileDialog<'_>) -> Option<PathBuf> {
+pub fn save_file(p: &FileDialog<'_>) -> Option<(PathBuf, FileSelection)> {
 	let title = p.title;
 	let path = p.path;
 	let filters = p.filters;
+	let show_hidden = p.show_hidden;
+	let create_folders = p.create_folders;
+	let accept_label = p.accept_label;
+	let selection = utils::file_selection(p, None);
 	let owner = owner_handle(p.owner);
 
 	run_on_main(move |mtm| {
@@ -226,7 +237,11 @@ pub fn save_file(p: &FileDialog<'_>) -> Option<PathBuf> {
 		let panel = NSSavePanel::savePanel(mtm);
 		let title = NSString::from_str(title);
 		panel.setTitle(Some(&title));
-		panel.setCanCreateDirectories(true);
+		panel.setCanCreateDirectories(create_folders);
+		panel.setShowsHiddenFiles(show_hidden);
+		if let Some(accept_label) = accept_label {
+			panel.setPrompt(Some(&NSString::from_str(accept_label)));
+		}
 		apply_filters(&panel, filters);
 
 		let (directory, default_name) = initial_directory_and_name(path);
@@ -246,7 +261,7 @@ pub fn save_file(p: &FileDialog<'_>) -> Option<PathBuf> {
 			return None;
 		}
 
-		panel.URL().and_then(url_into_pathbuf)
+		panel.URL().and_then(url_into_pathbuf).map(|path| (path, selection))
 	})
 }
 
@@ -261,6 +276,9 @@ pub fn choose_folders(p: &FileDialog<'_>) -> Option<Vec<PathBuf>> {
 fn choose_folders_impl(p: &FileDialog<'_>, multiple: bool) -> Option<Vec<PathBuf>> {
 	let title_text = p.title;
 	let directory = p.path;
+	let show_hidden = p.show_hidden;
+	let create_folders = p.create_folders;
+	let accept_label = p.accept_label;
 	let owner = owner_handle(p.owner);
 
 	run_on_main(move |mtm| {
@@ -273,7 +291,11 @@ fn choose_folders_impl(p: &FileDialog<'_>, multiple: bool) -> Option<Vec<PathBuf
 		panel.setCanChooseDirectories(true);
 		panel.setCanChooseFiles(false);
 		panel.setAllowsMultipleSelection(multiple);
-		panel.setCanCreateDirectories(true);
+		panel.setCanCreateDirectories(create_folders);
+		panel.setShowsHiddenFiles(show_hidden);
+		if let Some(accept_label) = accept_label {
+			panel.setPrompt(Some(&NSString::from_str(accept_label)));
+		}
 
 		if let Some(directory) = directory {
 			if let Some(path) = utils::abspath(Some(directory)) {
@@ -372,6 +394,7 @@ pub fn text_input(p: &TextInput<'_>) -> Option<String> {
 pub fn color_picker(p: &ColorPicker<'_>) -> Option<ColorValue> {
 	let title_text = p.title;
 	let initial = p.value;
+	let alpha = p.alpha;
 	let owner = owner_handle(p.owner);
 
 	run_on_main(move |mtm| {
@@ -390,7 +413,8 @@ pub fn color_picker(p: &ColorPicker<'_>) -> Option<ColorValue> {
 		let container = NSView::initWithFrame(NSView::alloc(mtm), color_well_container_frame());
 		let well = NSColorWell::initWithFrame(NSColorWell::alloc(mtm), color_well_frame());
 		let _ = NSColorWellStyle::Default;
-		let initial = color_value_to_nscolor(initial);
+		NSColorPanel::sharedColorPanel(mtm).setShowsAlpha(alpha);
+		let initial = color_value_to_nscolor(ColorValue { alpha: if alpha { initial.alpha } else { 255 }, ..initial });
 		well.setColor(&initial);
 		container.addSubview(&well);
 		alert.setAccessoryView(Some(&container));
@@ -401,10 +425,24 @@ pub fn color_picker(p: &ColorPicker<'_>) -> Option<ColorValue> {
 			return None;
 		}
 
-		nscolor_to_color_value(&well.color())
+		let color = nscolor_to_color_value(&well.color())?;
+		Some(if alpha { color } else { ColorValue { alpha: 255, ..color } })
 	})
 }
 
+pub fn app_chooser(p: &AppChooser<'_>) -> Option<String> {
+	// No application chooser panel, pick an application bundle instead.
+	let filters = [FileFilter { name: "Applications", patterns: &["*.app"], mime_types: &[] }];
+	let (app, _) = pick_file(&FileDialog {
+		title: p.title,
+		path: Some(Path::new("/Applications")),
+		filters: Some(&filters),
+		owner: p.owner,
+		..Default::default()
+	})?;
+	Some(app.to_string_lossy().into_owned())
+}
+
 #[inline]
 pub fn notify_setup(_app_id: &str) -> bool {
 	// No explicit setup required for NSUserNotificationCenter.
@@ -440,6 +478,9 @@ fn run_open_panel(p: &FileDialog<'_>, multiple: bool) -> Option<Vec<PathBuf>> {
 	let title_text = p.title;
 	let initial_path = p.path;
 	let filters = p.filters;
+	let show_hidden = p.show_hidden;
+	let create_folders = p.create_folders;
+	let accept_label = p.accept_label;
 	let owner = owner_handle(p.owner);
 
 	run_on_main(move |mtm| {
@@ -452,7 +493,11 @@ fn run_open_panel(p: &FileDialog<'_>, multiple: bool) -> Option<Vec<PathBuf>> {
 		panel.setCanChooseDirectories(false);
 		panel.setCanChooseFiles(true);
 		panel.setAllowsMultipleSelection(multiple);
-		panel.setCanCreateDirectories(true);
+		panel.setCanCreateDirectories(create_folders);
+		panel.setShowsHiddenFiles(show_hidden);
+		if let Some(accept_label) = accept_label {
+			panel.setPrompt(Some(&NSString::from_str(accept_label)));
+		}
 		apply_filters(&panel, filters);
 
 		if let Some(initial_path) = initial_path {
@@ -501,7 +546,7 @@ fn color_value_to_nscolor(color: ColorValue) -> rc::Retained<NSColor> {
 		u8_to_component(color.red),
 		u8_to_component(color.green),
 		u8_to_component(color.blue),
-		1.0,
+		u8_to_component(color.alpha),
 	)
 }
 
@@ -513,6 +558,7 @@ fn nscolor_to_color_value(color: &NSColor) -> Option<ColorValue> {
 		red: component_to_u8(color.redComponent()),
 		green: component_to_u8(color.greenComponent()),
 		blue: component_to_u8(color.blueComponent()),
+		alpha: component_to_u8(color.alphaComponent()),
 	})
 }
 
@@ -1,3 +1,5 @@
+use std::process;
+
 use super::*;
 
 #[cfg(feature = "appkit")]
@@ -9,3 +11,116 @@ pub use appkit::*;
 mod osascript;
 #[cfg(not(feature = "appkit"))]
 pub use osascript::*;
+
+#[inline]
+pub fn save_files(p: &FileDialog<'_>, names: &[&str]) -> Option<Vec<PathBuf>> {
+	utils::save_files(p, names)
+}
+
+#[inline]
+pub fn pick_uri(p: &FileDialog<'_>) -> Option<String> {
+	utils::pick_uri(p, pick_file)
+}
+
+#[inline]
+pub fn pick_uris(p: &FileDialog<'_>) -> Option<Vec<String>> {
+	utils::pick_uris(p, pick_files)
+}
+
+#[inline]
+pub fn save_uri(p: &FileDialog<'_>) -> Option<String> {
+	utils::save_uri(p)
+}
+
+#[inline]
+pub fn about_dialog(p: &AboutDialog<'_>) {
+	utils::about_dialog(p, message_box)
+}
+
+#[inline]
+pub fn text_viewer(p: &TextViewer<'_>) -> bool {
+	utils::text_viewer(p, message_box)
+}
+
+#[inline]
+pub fn login_dialog(p: &LoginDialog<'_>) -> Option<Credentials> {
+	utils::login_dialog(p, text_input)
+}
+
+#[inline]
+pub fn pick_color_from_screen(_p: &ColorPicker<'_>) -> Option<ColorValue> {
+	None
+}
+
+#[inline]
+pub fn print_dialog(_p: &PrintDialog<'_>) -> Option<PrintSettings> {
+	None
+}
+
+#[inline]
+pub fn print_file(_settings: &PrintSettings, _title: &str, _path: &Path) -> bool {
+	false
+}
+
+pub fn font_picker(p: &FontPicker<'_>) -> Option<FontValue> {
+	// No modal font chooser available, ask for a font description string instead.
+	let value = text_input(&TextInput {
+		title: p.title,
+		message: "Font (e.g. \"Helvetica Bold Italic 12\"):",
+		value: p.value,
+		mode: TextInputMode::SingleLine,
+		owner: p.owner,
+	})?;
+
+	let font = utils::parse_font(&value);
+	if font.family.is_empty() {
+		return None;
+	}
+	Some(font)
+}
+
+pub fn app_chooser_open(p: &AppChooser<'_>) -> bool {
+	let Some(app) = app_chooser(p) else {
+		return false;
+	};
+	process::Command::new("open").arg("-a").arg(app).arg("--").arg(p.path).status().is_ok_and(|status| status.success())
+}
+
+#[inline]
+pub fn icon_picker(p: &IconPicker<'_>) -> Option<IconValue> {
+	// No themed icons, ask for an image file instead.
+	utils::pick_icon_file(p.title, p.owner)
+}
+
+pub fn desktop_appearance() -> Appearance {
+	let defaults = |domain: &str, key: &str| {
+		let output = process::Command::new("defaults").args(["read", domain, key]).output().ok()?;
+		let value = String::from_utf8(output.stdout).ok()?;
+		output.status.success().then(|| value.trim().to_string())
+	};
+
+	// The interface style is only set in dark mode.
+	let color_scheme = match defaults("-g", "AppleInterfaceStyle").as_deref() {
+		Some("Dark") => ColorScheme::Dark,
+		_ => ColorScheme::Light,
+	};
+
+	// The accent color is stored as an index, the default (multicolor) is blue.
+	let accent_color = match defaults("-g", "AppleAccentColor").as_deref() {
+		Some("-1") => ColorValue::rgb(0x8E, 0x8E, 0x93),
+		Some("0") => ColorValue::rgb(0xFF, 0x3B, 0x30),
+		Some("1") => ColorValue::rgb(0xFF, 0x95, 0x00),
+		Some("2") => ColorValue::rgb(0xFF, 0xCC, 0x00),
+		Some("3") => ColorValue::rgb(0x28, 0xCD, 0x41),
+		Some("5") => ColorValue::rgb(0xAF, 0x52, 0xDE),
+		Some("6") => ColorValue::rgb(0xFF, 0x2D, 0x55),
+		_ => ColorValue::rgb(0x00, 0x7A, 0xFF),
+	};
+
+	let high_contrast = defaults("com.apple.universalaccess", "increaseContrast").as_deref() == Some("1");
+
+	Appearance { color_scheme, accent_color: Some(accent_color), high_contrast }
+}
+
+// No native log window yet, all methods do nothing.
+pub use crate::utils::NoLogWindow as LogWindowImpl;
@@ -55,12 +55,14 @@ end run
 	}
 }
 
-pub fn pick_file(p: &FileDialog<'_>) -> Option<PathBuf> {
-	pick_files_impl(p, false).and_then(|files| files.into_iter().next())
+// The AppleScript dialogs have no filters and choices, so no filter is ever selected and the choices keep their default values.
+pub fn pick_file(p: &FileDialog<'_>) -> Option<(PathBuf, FileSelection)> {
+	let path = pick_files_impl(p, false)?.into_iter().next()?;
+	Some((path, utils::file_selection(p, None)))
 }
 
-pub fn pick_files(p: &FileDialog<'_>) -> Option<Vec<PathBuf>> {
-	pick_files_impl(p, true)
+pub fn pick_files(p: &FileDialog<'_>) -> Option<(Vec<PathBuf>, FileSelection)> {
+	pick_files_impl(p, true).map(|paths| (paths, utils::file_selection(p, None)))
 }
 
 fn pick_files_impl(p: &FileDialog<'_>, multiple: bool) -> Option<Vec<PathBuf>> {
@@ -72,11 +74,12 @@ fn pick_files_impl(p: &FileDialog<'_>, multiple: bool) -> Option<Vec<PathBuf>> {
 on run argv
 	set theTitle to item 1 of argv
 	set initialPath to item 2 of argv
+	set showHidden to (item 3 of argv) is "true"
 
 	if initialPath is "" then
-		set selectedFile to choose file with prompt theTitle
+		set selectedFile to choose file with prompt theTitle invisibles showHidden
 	else
-		set selectedFile to choose file with prompt theTitle default location (POSIX file initialPath)
+		set selectedFile to choose file with prompt theTitle default location (POSIX file initialPath) invisibles showHidden
 	end if
 
 	return POSIX path of selectedFile
@@ -87,11 +90,12 @@ end run
 on run argv
 	set theTitle to item 1 of argv
 	set initialPath to item 2 of argv
+	set showHidden to (item 3 of argv) is "true"
 
 	if initialPath is "" then
-		set selectedFiles to choose file with prompt theTitle with multiple selections allowed true
+		set selectedFiles to choose file with prompt theTitle invisibles showHidden with multiple selections allowed true
 	else
-		set selectedFiles to choose file with prompt theTitle default location (POSIX file initialPath) with multiple selections allowed true
+		set selectedFiles to choose file with prompt theTitle default location (POSIX file initialPath) invisibles showHidden with multiple selections allowed true
 	end if
 
 	set outputLines to {}
@@ -108,7 +112,7 @@ end run
 "#;
 
 	let script = if multiple { script_multi } else { script_single };
-	let output = invoke_output(script, &[p.title, &initial_directory])?;
+	let output = invoke_output(script, &[p.title, &initial_directory, show_hidden(p)])?;
 
 	let paths = output.lines().map(PathBuf::from).collect::<Vec<_>>();
 	if paths.is_empty() {
@@ -117,7 +121,11 @@ end run
 	Some(paths)
 }
 
-pub fn save_file(p: &FileDialog<'_>) -> Option<PathBuf> {
+fn show_hidden(p: &FileDialog<'_>) -> &'static str {
+	if p.show_hidden { "true" } else { "false" }
+}
+
+pub fn save_file(p: &FileDialog<'_>) -> Option<(PathBuf, FileSelection)> {
 	let (initial_directory, default_name) = initial_directory_and_name(p.path);
 	let initial_directory = initial_directory
 		.map(|path| path.to_string_lossy().into_owned())
@@ -152,7 +160,7 @@ end run
 	if path.is_empty() {
 		return None;
 	}
-	Some(PathBuf::from(path))
+	Some((PathBuf::from(path), utils::file_selection(p, None)))
 }
 
 pub fn choose_folder(p: &FileDialog<'_>) -> Option<PathBuf> {
@@ -164,18 +172,19 @@ pub fn choose_folder(p: &FileDialog<'_>) -> Option<PathBuf> {
 on run argv
 	set theTitle to item 1 of argv
 	set initialPath to item 2 of argv
+	set showHidden to (item 3 of argv) is "true"
 
 	if initialPath is "" then
-		set selectedFolder to choose folder with prompt theTitle
+		set selectedFolder to choose folder with prompt theTitle invisibles showHidden
 	else
-		set selectedFolder to choose folder with prompt theTitle default location (POSIX file initialPath)
+		set selectedFolder to choose folder with prompt theTitle default location (POSIX file initialPath) invisibles showHidden
 	end if
 
 	return POSIX path of selectedFolder
 end run
 "#;
 
-	let path = invoke_output(script, &[p.title, initial_directory])?;
+	let path = invoke_output(script, &[p.title, initial_directory, show_hidden(p)])?;
 	if path.is_empty() {
 		return None;
 	}
@@ -191,11 +200,12 @@ pub fn choose_folders(p: &FileDialog<'_>) -> Option<Vec<PathBuf>> {
 on run argv
 	set theTitle to item 1 of argv
 	set initialPath to item 2 of argv
+	set showHidden to (item 3 of argv) is "true"
 
 	if initialPath is "" then
-		set selectedFolders to choose folder with prompt theTitle with multiple selections allowed
+		set selectedFolders to choose folder with prompt theTitle invisibles showHidden with multiple selections allowed
 	else
-		set selectedFolders to choose folder with prompt theTitle default location (POSIX file initialPath) with multiple selections allowed
+		set selectedFolders to choose folder with prompt theTitle default location (POSIX file initialPath) invisibles showHidden with multiple selections allowed
 	end if
 
 	set outputLines to {}
@@ -211,7 +221,7 @@ on run argv
 end run
 "#;
 
-	let output = invoke_output(script, &[p.title, initial_directory])?;
+	let output = invoke_output(script, &[p.title, initial_directory, show_hidden(p)])?;
 	let paths = output.lines().filter(|line| !line.is_empty()).map(PathBuf::from).collect::<Vec<_>>();
 	if paths.is_empty() {
 		return None;
@@ -255,15 +265,33 @@ on run argv
 	set blueValue to (item 4 of argv) as integer
 
 	set selectedColor to choose color default color {redValue, greenValue, blueValue}
-	set r to item 1 of selectedColor
-	set g to item 2 of selectedColor
-	set b to item 3 of selectedColor
-	return (r as string) & "," & (g as string) & "," & (b as string)
+	set r to (item 1 of selectedColor) div 257
+	set g to (item 2 of selectedColor) div 257
+	set b to (item 3 of selectedColor) div 257
+	return "rgb(" & r & ", " & g & ", " & b & ")"
 end run
 "#;
 
 	let output = invoke_output(script, &[p.title, &red, &green, &blue])?;
-	parse_color(&output)
+	// The color panel has no alpha, keep the alpha of the initial value.
+	let alpha = if p.alpha { p.value.alpha } else { 255 };
+	output.parse::<ColorValue>().ok().map(|color| ColorValue { alpha, ..color })
+}
+
+pub fn app_chooser(p: &AppChooser<'_>) -> Option<String> {
+	let file_name = p.path.file_name().unwrap_or(p.path.as_os_str()).to_string_lossy();
+	let prompt = format!("Open \u{201c}{file_name}\u{201d} with:");
+
+	let script = r#"
+on run argv
+	set theTitle to item 1 of argv
+	set thePrompt to item 2 of argv
+	set selectedApp to choose application with title theTitle with prompt thePrompt as alias
+	return POSIX path of selectedApp
+end run
+"#;
+
+	invoke_output(script, &[p.title, &prompt]).filter(|path| !path.is_empty())
 }
 
 #[inline]
@@ -326,20 +354,3 @@ fn initial_directory_and_name(initial_path: Option<&Path>) -> (Option<PathBuf>,
 	let file_name = path.file_name().and_then(|name| name.to_str()).map(str::to_string);
 	(directory, file_name)
 }
-
-fn parse_color(value: &str) -> Option<ColorValue> {
-	let mut parts = value.trim().split(',').map(str::trim);
-	let red16 = parts.next()?.parse::<u16>().ok()?;
-	let green16 = parts.next()?.parse::<u16>().ok()?;
-	let blue16 = parts.next()?.parse::<u16>().ok()?;
-
-	if parts.next().is_some() {
-		return None;
-	}
-
-	Some(ColorValue {
-		red: (red16 / 257) as u8,
-		green: (green16 / 257) as u8,
-		blue: (blue16 / 257) as u8,
-	})
-}
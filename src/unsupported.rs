@@ -6,17 +6,37 @@ pub fn message_box(_p: &MessageBox<'_>) -> Option<MessageResult> {
 }
 
 #[inline]
-pub fn pick_file(_p: &FileDialog<'_>) -> Option<PathBuf> {
+pub fn pick_file(_p: &FileDialog<'_>) -> Option<(PathBuf, FileSelection)> {
 	None
 }
 
 #[inline]
-pub fn pick_files(_p: &FileDialog<'_>) -> Option<Vec<PathBuf>> {
+pub fn pick_files(_p: &FileDialog<'_>) -> Option<(Vec<PathBuf>, FileSelection)> {
 	None
 }
 
 #[inline]
-pub fn save_file(_p: &FileDialog<'_>) -> Option<PathBuf> {
+pub fn save_file(_p: &FileDialog<'_>) -> Option<(PathBuf, FileSelection)> {
+	None
+}
+
+#[inline]
+pub fn save_files(_p: &FileDialog<'_>, _names: &[&str]) -> Option<Vec<PathBuf>> {
+	None
+}
+
+#[inline]
+pub fn pick_uri(_p: &FileDialog<'_>) -> Option<String> {
+	None
+}
+
+#[inline]
+pub fn pick_uris(_p: &FileDialog<'_>) -> Option<Vec<String>> {
+	None
+}
+
+#[inline]
+pub fn save_uri(_p: &FileDialog<'_>) -> Option<String> {
 	None
 }
 
@@ -35,11 +55,67 @@ pub fn text_input(_p: &TextInput<'_>) -> Option<String> {
 	None
 }
 
+#[inline]
+pub fn text_viewer(_p: &TextViewer<'_>) -> bool {
+	false
+}
+
+#[inline]
+pub fn login_dialog(_p: &LoginDialog<'_>) -> Option<Credentials> {
+	None
+}
+
+#[inline]
+pub fn about_dialog(_p: &AboutDialog<'_>) {
+}
+
 #[inline]
 pub fn color_picker(_p: &ColorPicker<'_>) -> Option<ColorValue> {
 	None
 }
 
+#[inline]
+pub fn pick_color_from_screen(_p: &ColorPicker<'_>) -> Option<ColorValue> {
+	None
+}
+
+#[inline]
+pub fn font_picker(_p: &FontPicker<'_>) -> Option<FontValue> {
+	None
+}
+
+#[inline]
+pub fn print_dialog(_p: &PrintDialog<'_>) -> Option<PrintSettings> {
+	None
+}
+
+#[inline]
+pub fn print_file(_settings: &PrintSettings, _title: &str, _path: &Path) -> bool {
+	false
+}
+
+#[inline]
+pub fn app_chooser(_p: &AppChooser<'_>) -> Option<String> {
+	None
+}
+
+#[inline]
+pub fn app_chooser_open(_p: &AppChooser<'_>) -> bool {
+	false
+}
+
+#[inline]
+pub fn icon_picker(_p: &IconPicker<'_>) -> Option<IconValue> {
+	None
+}
+
+#[inline]
+pub fn desktop_appearance() -> Appearance {
+	Appearance::default()
+}
+
+pub use crate::utils::NoLogWindow as LogWindowImpl;
+
 #[inline]
 pub fn notify_setup(_app_id: &str) -> bool {
 	false
@@ -1,6 +1,7 @@
-use std::{env, fmt};
-use std::path::Path;
+use std::{env, fmt, fs};
+use std::path::{Path, PathBuf};
 use std::borrow::Cow;
+use std::sync::atomic::{AtomicU8, Ordering};
 
 #[allow(dead_code)]
 pub struct FromFn<F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result>(pub F);
@@ -41,3 +42,715 @@ pub fn abspath(path: Option<&Path>) -> Option<Cow<'_, Path>> {
 		}
 	})
 }
+
+/// The filters shown in a file dialog, including the "All Files" filter if requested.
+#[allow(dead_code)]
+pub fn dialog_filters<'a>(p: &crate::FileDialog<'a>) -> Vec<crate::FileFilter<'a>> {
+	let Some(filters) = p.filters else {
+		return Vec::new();
+	};
+	let mut result = filters.to_vec();
+	if p.all_files {
+		result.push(crate::FileFilter::ALL_FILES);
+	}
+	result
+}
+
+/// Index of the filter selected when the dialog opens, `None` without filters.
+#[allow(dead_code)]
+pub fn default_filter(p: &crate::FileDialog<'_>) -> Option<usize> {
+	let count = p.filters?.len();
+	if count == 0 {
+		return None;
+	}
+	Some(if p.default_filter < count { p.default_filter } else { 0 })
+}
+
+/// The filters for backends which always select the first filter, the default filter is moved to the front.
+#[allow(dead_code)]
+pub fn default_first_filters<'a>(p: &crate::FileDialog<'a>) -> Vec<crate::FileFilter<'a>> {
+	let mut filters = dialog_filters(p);
+	if let Some(default) = default_filter(p) {
+		filters[..=default].rotate_right(1);
+	}
+	filters
+}
+
+/// Maps an index into [`dialog_filters`] to an index into [`crate::FileDialog::filters`], the "All Files" filter maps to `None`.
+#[allow(dead_code)]
+pub fn filter_index(p: &crate::FileDialog<'_>, index: usize) -> Option<usize> {
+	(index < p.filters?.len()).then_some(index)
+}
+
+/// Guesses the selected filter from the file name for backends which do not report it.
+///
+/// The `filter_patterns` function returns the patterns of a filter including its translated MIME types.
+#[allow(dead_code)]
+pub fn guess_filter(p: &crate::FileDialog<'_>, path: &Path, filter_patterns: fn(&crate::FileFilter<'_>) -> Vec<String>) -> Option<usize> {
+	let filters = p.filters?;
+	let name = path.file_name()?.to_str()?;
+	let matches = |filter: &crate::FileFilter<'_>| filter_patterns(filter).iter().any(|pattern| glob_match(pattern, name));
+
+	let default = default_filter(p)?;
+	if matches(&filters[default]) {
+		return Some(default);
+	}
+	filters.iter().position(matches)
+}
+
+/// The selection for backends without choices, the choices have their default values.
+#[allow(dead_code)]
+pub fn file_selection(p: &crate::FileDialog<'_>, filter: Option<usize>) -> crate::FileSelection {
+	let choices = p.choices.iter().map(|choice| (choice.id.to_string(), choice.default.to_string())).collect();
+	crate::FileSelection { filter, choices }
+}
+
+/// Shows the save file dialog and appends the default extension when the entered file name has none.
+pub fn save_file(p: &crate::FileDialog<'_>) -> Option<(PathBuf, crate::FileSelection)> {
+	let mut retry: Option<PathBuf> = None;
+	loop {
+		let dialog = crate::FileDialog { path: retry.as_deref().or(p.path), ..*p };
+		let (path, selection) = crate::save_file(&dialog)?;
+
+		let Some(extension) = save_extension(p, selection.filter) else {
+			return Some((path, selection));
+		};
+		if path.extension().is_some_and(|extension| !extension.is_empty()) {
+			return Some((path, selection));
+		}
+
+		// The dialog only confirmed overwriting the name without extension.
+		let path = path.with_extension(extension);
+		if !p.confirm_overwrite || !path.exists() || confirm_overwrite(p, &path) {
+			return Some((path, selection));
+		}
+		retry = Some(path);
+	}
+}
+
+fn save_extension<'a>(p: &crate::FileDialog<'a>, filter: Option<usize>) -> Option<&'a str> {
+	let default_extension = p.default_extension?;
+	let filter_extension = filter
+		.and_then(|index| p.filters?.get(index)?.patterns.first()?.strip_prefix("*."))
+		.filter(|extension| !extension.is_empty() && !extension.contains(['*', '?', '[', '{', '.']));
+	Some(filter_extension.unwrap_or(default_extension))
+}
+
+/// Whether all names are plain file names, which cannot escape the folder they are saved into.
+pub fn valid_file_names(names: &[&str]) -> bool {
+	names.iter().all(|name| {
+		let mut components = Path::new(name).components();
+		matches!((components.next(), components.next()), (Some(std::path::Component::Normal(_)), None))
+			&& !name.contains(std::path::is_separator)
+	})
+}
+
+/// Saves multiple files into a folder chosen with the folder dialog, for backends without a native dialog.
+///
+/// Asks before replacing existing files, the folder dialog is shown again if the user declines.
+pub fn save_files(p: &crate::FileDialog<'_>, names: &[&str]) -> Option<Vec<PathBuf>> {
+	if !valid_file_names(names) {
+		return None;
+	}
+	let mut retry: Option<PathBuf> = None;
+	loop {
+		let dialog = crate::FileDialog { path: retry.as_deref().or(p.path), ..*p };
+		let directory = crate::choose_folder(&dialog)?;
+		let paths = names.iter().map(|name| directory.join(name)).collect::<Vec<_>>();
+		if confirm_overwrite_files(p, &paths) {
+			return Some(paths);
+		}
+		retry = Some(directory);
+	}
+}
+
+/// Asks before replacing the existing files among `paths`, unless [`crate::FileDialog::confirm_overwrite`] is disabled.
+pub fn confirm_overwrite_files(p: &crate::FileDialog<'_>, paths: &[PathBuf]) -> bool {
+	if !p.confirm_overwrite {
+		return true;
+	}
+	let existing = paths.iter().filter(|path| path.exists()).collect::<Vec<_>>();
+	match existing[..] {
+		[] => true,
+		[path] => confirm_overwrite(p, path),
+		[path, ..] => {
+			let directory = path.parent().unwrap_or(path);
+			let name = directory.file_name().unwrap_or(directory.as_os_str()).to_string_lossy();
+			let message = format!("{} files already exist in \"{name}\". Do you want to replace them?", existing.len());
+			confirm_replace(p, &message)
+		}
+	}
+}
+
+/// Asks whether to replace the existing file at `path`.
+pub fn confirm_overwrite(p: &crate::FileDialog<'_>, path: &Path) -> bool {
+	let name = path.file_name().unwrap_or(path.as_os_str()).to_string_lossy();
+	let message = format!("A file named \"{name}\" already exists. Do you want to replace it?");
+	confirm_replace(p, &message)
+}
+
+fn confirm_replace(p: &crate::FileDialog<'_>, message: &str) -> bool {
+	let result = crate::MessageBox {
+		title: p.title,
+		message,
+		icon: crate::MessageIcon::Warning,
+		buttons: crate::MessageButtons::YesNo,
+		owner: p.owner,
+	}.show();
+	result == Some(crate::MessageResult::Yes)
+}
+
+/// Converts an absolute path into a `file://` URI, percent-encoding everything except unreserved characters and `/`.
+pub fn file_uri(path: &Path) -> String {
+	let bytes = path.as_os_str().as_encoded_bytes();
+	let mut bytes = Cow::Borrowed(bytes);
+	if cfg!(windows) {
+		let path = bytes.iter().map(|&byte| if byte == b'\\' { b'/' } else { byte }).collect::<Vec<_>>();
+		// Drive paths get an empty authority, UNC paths `//server/share` use the server as authority.
+		bytes = Cow::Owned(if path.starts_with(b"//") { path[2..].to_vec() } else { [b"/", &path[..]].concat() });
+	}
+
+	let mut uri = String::from("file://");
+	for &byte in bytes.iter() {
+		if byte.is_ascii_alphanumeric() || b"-._~/".contains(&byte) || (cfg!(windows) && byte == b':') {
+			uri.push(byte as char);
+		}
+		else {
+			uri.push_str(&format!("%{byte:02X}"));
+		}
+	}
+	uri
+}
+
+/// Shows the open file dialog and returns the `file://` URI, for backends which only select local files.
+pub fn pick_uri(p: &crate::FileDialog<'_>, pick_file: fn(&crate::FileDialog<'_>) -> Option<(PathBuf, crate::FileSelection)>) -> Option<String> {
+	pick_file(p).map(|(path, _)| file_uri(&path))
+}
+
+/// Shows the open files dialog and returns the `file://` URIs, for backends which only select local files.
+pub fn pick_uris(p: &crate::FileDialog<'_>, pick_files: fn(&crate::FileDialog<'_>) -> Option<(Vec<PathBuf>, crate::FileSelection)>) -> Option<Vec<String>> {
+	pick_files(p).map(|(paths, _)| paths.iter().map(|path| file_uri(path)).collect())
+}
+
+/// Shows the save file dialog and returns the `file://` URI, for backends which only select local files.
+pub fn save_uri(p: &crate::FileDialog<'_>) -> Option<String> {
+	save_file(p).map(|(path, _)| file_uri(&path))
+}
+
+/// Matches a file name against a glob pattern with `*`, `?` and `[...]` wildcards, ignoring ASCII case.
+pub fn glob_match(pattern: &str, name: &str) -> bool {
+	let pattern = pattern.chars().collect::<Vec<_>>();
+	let name = name.chars().collect::<Vec<_>>();
+
+	// Backtrack to the last `*` on a mismatch.
+	let (mut p, mut n) = (0, 0);
+	let mut star = None;
+	while n < name.len() {
+		let step = match pattern.get(p) {
+			Some('*') => {
+				star = Some((p, n));
+				p += 1;
+				continue;
+			}
+			Some('?') => Some(1),
+			Some('[') => match_bracket(&pattern[p..], name[n]),
+			Some(&chr) => chr.eq_ignore_ascii_case(&name[n]).then_some(1),
+			None => None,
+		};
+		match (step, star) {
+			(Some(step), _) => {
+				p += step;
+				n += 1;
+			}
+			(None, Some((star_p, star_n))) => {
+				star = Some((star_p, star_n + 1));
+				p = star_p + 1;
+				n = star_n + 1;
+			}
+			(None, None) => return false,
+		}
+	}
+	pattern[p..].iter().all(|&chr| chr == '*')
+}
+
+// Matches a character against the bracket expression at the start of the pattern, returns the length of the expression.
+// An unterminated bracket matches a literal `[`.
+fn match_bracket(pattern: &[char], chr: char) -> Option<usize> {
+	let negated = matches!(pattern.get(1), Some('!' | '^'));
+	let start = if negated { 2 } else { 1 };
+	// A `]` directly after the opening bracket is part of the set.
+	let Some(end) = pattern.iter().skip(start + 1).position(|&chr| chr == ']').map(|index| index + start + 1) else {
+		return (chr == '[').then_some(1);
+	};
+
+	let set = &pattern[start..end];
+	let chr = chr.to_ascii_lowercase();
+	let mut found = false;
+	let mut index = 0;
+	while index < set.len() {
+		if index + 2 < set.len() && set[index + 1] == '-' {
+			let (low, high) = (set[index].to_ascii_lowercase(), set[index + 2].to_ascii_lowercase());
+			found |= low <= chr && chr <= high;
+			index += 3;
+		}
+		else {
+			found |= set[index].to_ascii_lowercase() == chr;
+			index += 1;
+		}
+	}
+	(found != negated).then_some(end + 1)
+}
+
+/// The glob pattern syntax understood by a backend.
+#[allow(dead_code)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum GlobSyntax {
+	/// Case-sensitive globs with bracket expressions, e.g. GTK and the XDG portal.
+	///
+	/// Letters are matched case-insensitively with bracket expressions, e.g. `*.[jJ][pP][gG]`.
+	CaseSensitive,
+	/// Like `CaseSensitive` for backends which separate patterns by spaces, e.g. `zenity`.
+	///
+	/// Spaces in patterns are matched with `?`.
+	SpaceSeparated,
+	/// Like `CaseInsensitive` for backends which separate patterns by spaces, e.g. `kdialog`.
+	///
+	/// Spaces in patterns are matched with `?`.
+	CaseInsensitiveSpaceSeparated,
+	/// Case-insensitive globs without bracket expressions, e.g. Windows and macOS.
+	CaseInsensitive,
+}
+
+/// Normalizes the glob patterns of a filter for a backend.
+///
+/// Brace patterns are expanded, e.g. `*.{png,jpg}` becomes `*.png` and `*.jpg`, and the patterns are made to match case-insensitively.
+#[allow(dead_code)]
+pub fn glob_patterns<'a>(patterns: impl IntoIterator<Item = &'a str>, syntax: GlobSyntax) -> Vec<String> {
+	let mut result: Vec<String> = Vec::new();
+	for pattern in patterns {
+		for pattern in expand_braces(pattern.trim()) {
+			let pattern = match syntax {
+				GlobSyntax::CaseSensitive => case_insensitive_glob(&pattern),
+				GlobSyntax::SpaceSeparated => case_insensitive_glob(&pattern).replace(char::is_whitespace, "?"),
+				GlobSyntax::CaseInsensitive => pattern,
+				GlobSyntax::CaseInsensitiveSpaceSeparated => pattern.replace(char::is_whitespace, "?"),
+			};
+			if !pattern.is_empty() && !result.contains(&pattern) {
+				result.push(pattern);
+			}
+		}
+	}
+	result
+}
+
+/// Expands the first brace group in the pattern recursively, e.g. `*.{tar.{gz,xz},zip}`.
+///
+/// Patterns without a matching closing brace are returned unchanged.
+pub fn expand_braces(pattern: &str) -> Vec<String> {
+	let Some(open) = pattern.find('{') else {
+		return vec![pattern.to_string()];
+	};
+
+	// Find the matching closing brace and the top level commas.
+	let mut depth = 0;
+	let mut commas = Vec::new();
+	let mut close = None;
+	for (index, chr) in pattern[open..].char_indices().map(|(index, chr)| (index + open, chr)) {
+		match chr {
+			'{' => depth += 1,
+			'}' => {
+				depth -= 1;
+				if depth == 0 {
+					close = Some(index);
+					break;
+				}
+			}
+			',' if depth == 1 => commas.push(index),
+			_ => {}
+		}
+	}
+	let Some(close) = close else {
+		return vec![pattern.to_string()];
+	};
+
+	let (prefix, suffix) = (&pattern[..open], &pattern[close + 1..]);
+	let bounds = std::iter::once(open).chain(commas.iter().copied()).zip(commas.iter().copied().chain(std::iter::once(close)));
+	let mut result = Vec::new();
+	for (start, end) in bounds {
+		let alternative = format!("{prefix}{}{suffix}", &pattern[start + 1..end]);
+		result.extend(expand_braces(&alternative));
+	}
+	result
+}
+
+// Replaces letters outside bracket expressions with a bracket expression matching both cases.
+fn case_insensitive_glob(pattern: &str) -> String {
+	let mut result = String::with_capacity(pattern.len() * 4);
+	let mut chars = pattern.chars().peekable();
+	while let Some(chr) = chars.next() {
+		if chr == '[' {
+			// Copy existing bracket expressions verbatim.
+			result.push(chr);
+			if let Some(&next) = chars.peek() {
+				if next == '!' || next == '^' {
+					result.push(next);
+					chars.next();
+				}
+			}
+			if chars.peek() == Some(&']') {
+				result.push(']');
+				chars.next();
+			}
+			for chr in chars.by_ref() {
+				result.push(chr);
+				if chr == ']' {
+					break;
+				}
+			}
+		}
+		else if chr.is_ascii_alphabetic() {
+			result.push('[');
+			result.push(chr.to_ascii_lowercase());
+			result.push(chr.to_ascii_uppercase());
+			result.push(']');
+		}
+		else {
+			result.push(chr);
+		}
+	}
+	result
+}
+
+// Asks for an image file for backends without themed icons.
+#[allow(dead_code)]
+pub fn pick_icon_file(title: &str, owner: Option<&dyn raw_window_handle::HasWindowHandle>) -> Option<crate::IconValue> {
+	let filters = [crate::FileFilter::ICON_FILES];
+	let path = crate::FileDialog { title, filters: Some(&filters), owner, ..Default::default() }.pick_file()?;
+	Some(crate::IconValue::File(path))
+}
+
+const FONT_WEIGHTS: [(&str, u16); 17] = [
+	("Thin", 100),
+	("Ultra-Light", 200),
+	("Extra-Light", 200),
+	("Light", 300),
+	("Semi-Light", 350),
+	("Demi-Light", 350),
+	("Book", 380),
+	("Regular", 400),
+	("Medium", 500),
+	("Semi-Bold", 600),
+	("Demi-Bold", 600),
+	("Bold", 700),
+	("Ultra-Bold", 800),
+	("Extra-Bold", 800),
+	("Heavy", 900),
+	("Black", 900),
+	("Ultra-Heavy", 1000),
+];
+
+/// Returns the Pango name of the font weight closest to the given weight, or `None` for regular weight.
+pub fn font_weight_name(weight: u16) -> Option<&'static str> {
+	let &(name, value) = FONT_WEIGHTS.iter().min_by_key(|&&(_, value)| value.abs_diff(weight))?;
+	if value == 400 { None } else { Some(name) }
+}
+
+/// Returns the font weight for the given Pango weight name, e.g. `"Semi-Bold"` or `"SemiBold"`.
+#[allow(dead_code)]
+pub fn font_weight(name: &str) -> Option<u16> {
+	if name.eq_ignore_ascii_case("Normal") {
+		return Some(400);
+	}
+	FONT_WEIGHTS.iter()
+		.find(|(weight_name, _)| weight_name.eq_ignore_ascii_case(name) || weight_name.replace('-', "").eq_ignore_ascii_case(name))
+		.map(|&(_, weight)| weight)
+}
+
+/// Parses a Pango font description string such as `"Sans Bold Italic 12"`.
+///
+/// Style words are matched from the end of the string; the remaining words form the family name.
+/// Unknown style words (variants, stretches, gravities) are kept as part of the family name.
+#[allow(dead_code)]
+pub fn parse_font(desc: &str) -> crate::FontValue {
+	let mut font = crate::FontValue {
+		family: String::new(),
+		style: crate::FontStyle::Normal,
+		weight: 400,
+		size: 0.0,
+	};
+
+	let mut words: Vec<&str> = desc.split([' ', ',']).filter(|word| !word.is_empty()).collect();
+
+	if let Some(&last) = words.last() {
+		let number = last.strip_suffix("px").unwrap_or(last);
+		if let Ok(size) = number.parse::<f32>() {
+			font.size = size;
+			words.pop();
+		}
+	}
+
+	while let Some(&word) = words.last() {
+		if word.eq_ignore_ascii_case("Italic") {
+			font.style = crate::FontStyle::Italic;
+		}
+		else if word.eq_ignore_ascii_case("Oblique") {
+			font.style = crate::FontStyle::Oblique;
+		}
+		else if word.eq_ignore_ascii_case("Normal") || word.eq_ignore_ascii_case("Roman") {
+		}
+		else if let Some(weight) = font_weight(word) {
+			font.weight = weight;
+		}
+		else {
+			break;
+		}
+		words.pop();
+	}
+
+	font.family = words.join(" ");
+	font
+}
+
+/// Longest text shown by [`text_viewer`], message boxes cannot scroll.
+const MESSAGE_TEXT_LINES: usize = 30;
+const MESSAGE_TEXT_CHARS: usize = 3000;
+
+/// Shows the text in a message box and asks a follow-up question for the checkbox, for backends without a text viewer.
+///
+/// Long texts are cut off, a file is then offered by its path so it can be read in full.
+#[allow(dead_code)]
+pub fn text_viewer(p: &crate::TextViewer<'_>, message_box: fn(&crate::MessageBox<'_>) -> Option<crate::MessageResult>) -> bool {
+	let Some(text) = p.content.load() else {
+		return false;
+	};
+
+	let mut message = text.lines().take(MESSAGE_TEXT_LINES).collect::<Vec<_>>().join("\n");
+	let mut truncated = text.lines().nth(MESSAGE_TEXT_LINES).is_some();
+	if let Some((end, _)) = message.char_indices().nth(MESSAGE_TEXT_CHARS) {
+		message.truncate(end);
+		truncated = true;
+	}
+	if truncated {
+		message.push_str("\n...");
+		if let crate::TextContent::File(path) = p.content {
+			message.push_str(&format!("\n\nThe full text is in {}", path.display()));
+		}
+	}
+
+	let result = message_box(&crate::MessageBox {
+		title: p.title,
+		message: &message,
+		icon: crate::MessageIcon::Info,
+		buttons: crate::MessageButtons::OkCancel,
+		owner: p.owner,
+	});
+	if result != Some(crate::MessageResult::Ok) {
+		return false;
+	}
+
+	match p.checkbox {
+		Some(checkbox) => message_box(&crate::MessageBox {
+			title: p.title,
+			message: checkbox,
+			icon: crate::MessageIcon::Question,
+			buttons: crate::MessageButtons::YesNo,
+			owner: p.owner,
+		}) == Some(crate::MessageResult::Yes),
+		None => true,
+	}
+}
+
+/// Asks for the user name first and the password second, for backends without a combined login dialog.
+#[allow(dead_code)]
+pub fn login_dialog(p: &crate::LoginDialog<'_>, text_input: fn(&crate::TextInput<'_>) -> Option<String>) -> Option<crate::Credentials> {
+	let username = text_input(&crate::TextInput {
+		title: p.title,
+		message: p.message,
+		value: p.username,
+		mode: crate::TextInputMode::SingleLine,
+		owner: p.owner,
+	})?;
+
+	let message = format!("Password for {username}:");
+	let password = text_input(&crate::TextInput {
+		title: p.title,
+		message: &message,
+		value: "",
+		mode: crate::TextInputMode::Password,
+		owner: p.owner,
+	})?;
+
+	Some(crate::Credentials { username, password })
+}
+
+/// Plain text summary of the about dialog for backends without a native about dialog.
+#[allow(dead_code)]
+pub fn about_text(p: &crate::AboutDialog<'_>) -> String {
+	let mut text = format!("{} {}", p.name, p.version).trim_end().to_string();
+	if !p.comments.is_empty() {
+		text.push_str("\n\n");
+		text.push_str(p.comments);
+	}
+	if !p.website.is_empty() {
+		text.push_str("\n\n");
+		text.push_str(p.website);
+	}
+	if !p.authors.is_empty() {
+		text.push_str("\n\nAuthors:");
+		for author in p.authors {
+			text.push('\n');
+			text.push_str(author);
+		}
+	}
+	text
+}
+
+/// Shows the [`about_text`] in a message box, for backends without a native about dialog.
+#[allow(dead_code)]
+pub fn about_dialog(p: &crate::AboutDialog<'_>, message_box: fn(&crate::MessageBox<'_>) -> Option<crate::MessageResult>) {
+	let title = format!("About {}", p.name);
+	let text = about_text(p);
+	message_box(&crate::MessageBox {
+		title: &title,
+		message: &text,
+		icon: crate::MessageIcon::Info,
+		buttons: crate::MessageButtons::Ok,
+		owner: p.owner,
+	});
+}
+
+/// Log window for backends without one, all methods do nothing.
+#[allow(dead_code)]
+pub struct NoLogWindow;
+
+#[allow(dead_code)]
+impl NoLogWindow {
+	#[inline]
+	pub fn new(_title: &str) -> NoLogWindow {
+		NoLogWindow
+	}
+
+	#[inline]
+	pub fn append_line(&mut self, _line: &str) {
+	}
+
+	#[inline]
+	pub fn set_title(&mut self, _title: &str) {
+	}
+
+	#[inline]
+	pub fn finish(&mut self) {
+	}
+
+	#[inline]
+	pub fn wait_closed(self) {
+	}
+}
+
+/// Directory for state persisted between runs, e.g. `$XDG_STATE_HOME/rustydialogs`.
+pub fn state_dir() -> Option<PathBuf> {
+	let base = if cfg!(windows) {
+		env::var_os("LOCALAPPDATA").map(PathBuf::from)
+	}
+	else if cfg!(target_os = "macos") {
+		env::var_os("HOME").map(|home| PathBuf::from(home).join("Library/Application Support"))
+	}
+	else {
+		env::var_os("XDG_STATE_HOME")
+			.filter(|dir| !dir.is_empty())
+			.map(PathBuf::from)
+			.or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/state")))
+	};
+	Some(base?.join("rustydialogs"))
+}
+
+/// Path of a state file, the key is sanitized so it can be used as a file name.
+fn state_file(prefix: &str, key: &str) -> Option<PathBuf> {
+	let key = key.chars()
+		.map(|chr| if chr.is_ascii_alphanumeric() || chr == '-' || chr == '_' || chr == '.' { chr } else { '_' })
+		.collect::<String>();
+	Some(state_dir()?.join(format!("{prefix}-{key}")))
+}
+
+/// Maximum number of recent colors remembered per key.
+pub const MAX_RECENT_COLORS: usize = 16;
+
+/// Recent colors picked with the given key, most recent first.
+#[allow(dead_code)]
+pub fn recent_colors(key: Option<&str>) -> Vec<crate::ColorValue> {
+	let Some(path) = key.and_then(|key| state_file("colors", key)) else {
+		return Vec::new();
+	};
+	let Ok(contents) = fs::read_to_string(path) else {
+		return Vec::new();
+	};
+	contents.lines()
+		.filter_map(|line| line.parse().ok())
+		.take(MAX_RECENT_COLORS)
+		.collect()
+}
+
+/// Remembers a picked color with the given key.
+pub fn save_recent_color(key: &str, color: crate::ColorValue) {
+	let Some(path) = state_file("colors", key) else {
+		return;
+	};
+	let mut colors = recent_colors(Some(key));
+	colors.retain(|&recent| recent != color);
+	colors.insert(0, color);
+	colors.truncate(MAX_RECENT_COLORS);
+
+	let contents = colors.iter()
+		.map(|color| format!("{color}\n"))
+		.collect::<String>();
+	// Failing to remember the color is not worth failing the dialog over.
+	if let Some(dir) = path.parent() {
+		let _ = fs::create_dir_all(dir);
+	}
+	let _ = fs::write(path, contents);
+}
+
+/// Last directory used by file dialogs with the given key.
+pub fn last_directory(key: Option<&str>) -> Option<PathBuf> {
+	let path = state_file("directory", key?)?;
+	let contents = fs::read_to_string(path).ok()?;
+	let directory = PathBuf::from(contents.lines().next()?);
+	directory.is_dir().then_some(directory)
+}
+
+/// Remembers the directory used by a file dialog with the given key.
+pub fn save_last_directory(key: &str, directory: &Path) {
+	let Some(path) = state_file("directory", key) else {
+		return;
+	};
+	// Paths which are not valid UTF-8 are not remembered.
+	let Some(directory) = abspath(Some(directory)).and_then(|directory| Some(format!("{}\n", directory.to_str()?))) else {
+		return;
+	};
+	if let Some(dir) = path.parent() {
+		let _ = fs::create_dir_all(dir);
+	}
+	let _ = fs::write(path, directory);
+}
+
+static COLOR_SCHEME: AtomicU8 = AtomicU8::new(0);
+
+/// Remembers the color scheme forced with [`crate::set_color_scheme`].
+pub fn set_color_scheme(color_scheme: crate::ColorScheme) {
+	let value = match color_scheme {
+		crate::ColorScheme::NoPreference => 0,
+		crate::ColorScheme::Light => 1,
+		crate::ColorScheme::Dark => 2,
+	};
+	COLOR_SCHEME.store(value, Ordering::Relaxed);
+}
+
+/// The color scheme forced with [`crate::set_color_scheme`].
+#[allow(dead_code)]
+pub fn color_scheme() -> crate::ColorScheme {
+	match COLOR_SCHEME.load(Ordering::Relaxed) {
+		1 => crate::ColorScheme::Light,
+		2 => crate::ColorScheme::Dark,
+		_ => crate::ColorScheme::NoPreference,
+	}
+}
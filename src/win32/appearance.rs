@@ -0,0 +1,57 @@
+use std::ffi::c_void;
+
+use windows::core::PCWSTR;
+use windows::Win32::System::Registry::{RegGetValueW, HKEY_CURRENT_USER, RRF_RT_REG_DWORD};
+use windows::Win32::UI::Accessibility::{HCF_HIGHCONTRASTON, HIGHCONTRASTW};
+use windows::Win32::UI::WindowsAndMessaging::{SystemParametersInfoW, SPI_GETHIGHCONTRAST, SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS};
+
+use super::*;
+
+pub fn appearance() -> Appearance {
+	let color_scheme = match registry_dword(r"Software\Microsoft\Windows\CurrentVersion\Themes\Personalize", "AppsUseLightTheme") {
+		Some(0) => ColorScheme::Dark,
+		Some(_) => ColorScheme::Light,
+		None => ColorScheme::NoPreference,
+	};
+
+	// The accent color is stored as 0xAABBGGRR.
+	let accent_color = registry_dword(r"Software\Microsoft\Windows\DWM", "AccentColor").map(|abgr| {
+		let [red, green, blue, _] = abgr.to_le_bytes();
+		ColorValue::rgb(red, green, blue)
+	});
+
+	let mut contrast = HIGHCONTRASTW {
+		cbSize: std::mem::size_of::<HIGHCONTRASTW>() as u32,
+		..Default::default()
+	};
+	let ok = unsafe {
+		SystemParametersInfoW(
+			SPI_GETHIGHCONTRAST,
+			contrast.cbSize,
+			Some(&mut contrast as *mut HIGHCONTRASTW as *mut c_void),
+			SYSTEM_PARAMETERS_INFO_UPDATE_FLAGS(0),
+		).is_ok()
+	};
+	let high_contrast = ok && contrast.dwFlags.contains(HCF_HIGHCONTRASTON);
+
+	Appearance { color_scheme, accent_color, high_contrast }
+}
+
+fn registry_dword(key: &str, value: &str) -> Option<u32> {
+	let key = utf16cs(key);
+	let value = utf16cs(value);
+	let mut data = 0u32;
+	let mut size = std::mem::size_of::<u32>() as u32;
+	let result = unsafe {
+		RegGetValueW(
+			HKEY_CURRENT_USER,
+			PCWSTR(key.as_ptr()),
+			PCWSTR(value.as_ptr()),
+			RRF_RT_REG_DWORD,
+			None,
+			Some(&mut data as *mut u32 as *mut c_void),
+			Some(&mut size),
+		)
+	};
+	result.is_ok().then_some(data)
+}
@@ -6,7 +6,11 @@ use windows::Win32::UI::Controls::Dialogs::{
 use super::*;
 
 pub fn color_picker(p: &ColorPicker<'_>) -> Option<ColorValue> {
-	let mut custom_colors = [COLORREF(0); 16];
+	let mut custom_colors = [COLORREF(0x00FFFFFF); 16];
+	let recent = utils::recent_colors(p.key);
+	for (custom, &color) in custom_colors.iter_mut().zip(p.palette.iter().chain(&recent)) {
+		*custom = COLORREF(color_value_to_colorref(color));
+	}
 	let initial = COLORREF(color_value_to_colorref(p.value));
 
 	let mut picker = CHOOSECOLORW::default();
@@ -21,7 +25,9 @@ pub fn color_picker(p: &ColorPicker<'_>) -> Option<ColorValue> {
 		return None;
 	}
 
-	Some(colorref_to_color_value(picker.rgbResult))
+	// The color dialog has no alpha, keep the alpha of the initial value.
+	let alpha = if p.alpha { p.value.alpha } else { 255 };
+	Some(ColorValue { alpha, ..colorref_to_color_value(picker.rgbResult) })
 }
 
 fn color_value_to_colorref(color: ColorValue) -> u32 {
@@ -33,5 +39,6 @@ fn colorref_to_color_value(colorref: COLORREF) -> ColorValue {
 		red: (colorref.0 & 0xFF) as u8,
 		green: ((colorref.0 >> 8) & 0xFF) as u8,
 		blue: ((colorref.0 >> 16) & 0xFF) as u8,
+		alpha: 255,
 	}
 }
@@ -1,22 +1,24 @@
 use windows::core::{PCWSTR, PWSTR};
 use windows::Win32::UI::Controls::Dialogs::{
 	GetOpenFileNameW, GetSaveFileNameW, OPENFILENAMEW, OFN_ALLOWMULTISELECT, OFN_EXPLORER,
-	OFN_FILEMUSTEXIST, OFN_NOCHANGEDIR, OFN_OVERWRITEPROMPT, OFN_PATHMUSTEXIST,
+	OFN_FILEMUSTEXIST, OFN_FORCESHOWHIDDEN, OFN_NOCHANGEDIR, OFN_OVERWRITEPROMPT, OFN_PATHMUSTEXIST,
 };
 
 use super::*;
 
-pub fn pick_file(p: &FileDialog<'_>) -> Option<PathBuf> {
-	pick_files_impl(p, false).and_then(|paths| paths.into_iter().next())
+pub fn pick_file(p: &FileDialog<'_>) -> Option<(PathBuf, FileSelection)> {
+	let (paths, filter) = pick_files_impl(p, false)?;
+	Some((paths.into_iter().next()?, filter))
 }
 
-pub fn pick_files(p: &FileDialog<'_>) -> Option<Vec<PathBuf>> {
+pub fn pick_files(p: &FileDialog<'_>) -> Option<(Vec<PathBuf>, FileSelection)> {
 	pick_files_impl(p, true)
 }
 
-pub fn save_file(p: &FileDialog<'_>) -> Option<PathBuf> {
+pub fn save_file(p: &FileDialog<'_>) -> Option<(PathBuf, FileSelection)> {
 	let title = utf16cs(p.title);
-	let filters = build_windows_filter(p.filters);
+	let filters = build_windows_filter(p);
+	let default_extension = p.default_extension.map(utf16cs);
 	let path = utils::abspath(p.path);
 	let mut file_buffer = initial_file_buffer(path.as_deref());
 
@@ -25,23 +27,35 @@ pub fn save_file(p: &FileDialog<'_>) -> Option<PathBuf> {
 	open_file_name.lpstrTitle = PCWSTR(title.as_ptr());
 	if let Some(filters) = &filters {
 		open_file_name.lpstrFilter = PCWSTR(filters.as_ptr());
+		open_file_name.nFilterIndex = utils::default_filter(p).map_or(0, |index| index as u32 + 1);
+	}
+	// The dialog appends the extension of the selected filter itself and confirms overwriting the final name.
+	if let Some(default_extension) = &default_extension {
+		open_file_name.lpstrDefExt = PCWSTR(default_extension.as_ptr());
 	}
 	open_file_name.hwndOwner = hwnd(p.owner).unwrap_or_default();
 	open_file_name.lpstrFile = PWSTR(file_buffer.as_mut_ptr());
 	open_file_name.nMaxFile = file_buffer.len() as u32;
-	open_file_name.Flags = OFN_EXPLORER | OFN_NOCHANGEDIR | OFN_PATHMUSTEXIST | OFN_OVERWRITEPROMPT;
+	open_file_name.Flags = OFN_EXPLORER | OFN_NOCHANGEDIR | OFN_PATHMUSTEXIST;
+	if p.confirm_overwrite {
+		open_file_name.Flags |= OFN_OVERWRITEPROMPT;
+	}
+	if p.show_hidden {
+		open_file_name.Flags |= OFN_FORCESHOWHIDDEN;
+	}
 
 	let selected = unsafe { GetSaveFileNameW(&mut open_file_name).as_bool() };
 	if !selected {
 		return None;
 	}
 
-	wide_to_string_until_nul(&file_buffer)
+	let path = wide_to_string_until_nul(&file_buffer)?;
+	Some((path, utils::file_selection(p, selected_filter(p, &open_file_name))))
 }
 
-fn pick_files_impl(p: &FileDialog<'_>, allow_multiple_selects: bool) -> Option<Vec<PathBuf>> {
+fn pick_files_impl(p: &FileDialog<'_>, allow_multiple_selects: bool) -> Option<(Vec<PathBuf>, FileSelection)> {
 	let title = utf16cs(p.title);
-	let filters = build_windows_filter(p.filters);
+	let filters = build_windows_filter(p);
 	let path = utils::abspath(p.path);
 	let mut file_buffer = initial_file_buffer(path.as_deref());
 
@@ -50,6 +64,7 @@ fn pick_files_impl(p: &FileDialog<'_>, allow_multiple_selects: bool) -> Option<V
 	open_file_name.lpstrTitle = PCWSTR(title.as_ptr());
 	if let Some(filters) = &filters {
 		open_file_name.lpstrFilter = PCWSTR(filters.as_ptr());
+		open_file_name.nFilterIndex = utils::default_filter(p).map_or(0, |index| index as u32 + 1);
 	}
 	open_file_name.hwndOwner = hwnd(p.owner).unwrap_or_default();
 	open_file_name.lpstrFile = PWSTR(file_buffer.as_mut_ptr());
@@ -58,13 +73,23 @@ fn pick_files_impl(p: &FileDialog<'_>, allow_multiple_selects: bool) -> Option<V
 	if allow_multiple_selects {
 		open_file_name.Flags |= OFN_ALLOWMULTISELECT;
 	}
+	if p.show_hidden {
+		open_file_name.Flags |= OFN_FORCESHOWHIDDEN;
+	}
 
 	let selected = unsafe { GetOpenFileNameW(&mut open_file_name).as_bool() };
 	if !selected {
 		return None;
 	}
 
-	parse_open_file_buffer(&file_buffer)
+	let paths = parse_open_file_buffer(&file_buffer)?;
+	Some((paths, utils::file_selection(p, selected_filter(p, &open_file_name))))
+}
+
+// The filter index is one-based, zero is the custom filter which is never used.
+fn selected_filter(p: &FileDialog<'_>, open_file_name: &OPENFILENAMEW) -> Option<usize> {
+	let index = (open_file_name.nFilterIndex as usize).checked_sub(1)?;
+	utils::filter_index(p, index)
 }
 
 fn initial_file_buffer(file: Option<&Path>) -> Vec<u16> {
@@ -83,21 +108,23 @@ fn initial_file_buffer(file: Option<&Path>) -> Vec<u16> {
 	buffer
 }
 
-fn build_windows_filter(filters: Option<&[FileFilter<'_>]>) -> Option<Vec<u16>> {
-	let filters = filters?;
+fn build_windows_filter(p: &FileDialog<'_>) -> Option<Vec<u16>> {
+	let filters = utils::dialog_filters(p);
+	if filters.is_empty() {
+		return None;
+	}
 
 	let mut spec = String::new();
-	for filter in filters {
+	for filter in &filters {
 		add_filter(&mut spec, filter);
 	}
-	add_filter(&mut spec, &FileFilter::ALL_FILES);
 	spec.push('\0');
 	Some(spec.encode_utf16().collect())
 }
 
 fn add_filter(result: &mut String, filter: &FileFilter) {
 	use std::fmt::Write as _;
-	_ = write!(result, "{}\0{}\0", filter.name, utils::PrintJoin { parts: filter.patterns, separator: ";" });
+	_ = write!(result, "{}\0{}\0", filter.name, filter_patterns(filter));
 }
 
 fn wide_to_string_until_nul(input: &[u16]) -> Option<PathBuf> {
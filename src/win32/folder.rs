@@ -3,7 +3,7 @@ use windows::Win32::Foundation::{HWND, LPARAM, MAX_PATH, WPARAM};
 use windows::Win32::System::Com::CoTaskMemFree;
 use windows::Win32::UI::Shell::{
 	SHBrowseForFolderW, SHGetPathFromIDListW, BFFM_INITIALIZED, BFFM_SETSELECTIONW,
-	BIF_NEWDIALOGSTYLE, BIF_RETURNONLYFSDIRS, BROWSEINFOW,
+	BIF_NEWDIALOGSTYLE, BIF_NONEWFOLDERBUTTON, BIF_RETURNONLYFSDIRS, BROWSEINFOW,
 };
 use windows::Win32::UI::WindowsAndMessaging::SendMessageW;
 
@@ -18,6 +18,9 @@ pub fn choose_folder(p: &FileDialog<'_>) -> Option<PathBuf> {
 	browse_info.pszDisplayName = PWSTR(display_name.as_mut_ptr());
 	browse_info.lpszTitle = PCWSTR(title.as_ptr());
 	browse_info.ulFlags = BIF_RETURNONLYFSDIRS | BIF_NEWDIALOGSTYLE;
+	if !p.create_folders {
+		browse_info.ulFlags |= BIF_NONEWFOLDERBUTTON;
+	}
 	browse_info.lpfn = Some(folder_browse_callback);
 	browse_info.lParam = LPARAM((p as *const FileDialog<'_>).expose_provenance() as isize);
 
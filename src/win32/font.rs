@@ -0,0 +1,41 @@
+use windows::Win32::Graphics::Gdi::LOGFONTW;
+use windows::Win32::UI::Controls::Dialogs::{
+	ChooseFontW, CHOOSEFONTW, CF_INITTOLOGFONTSTRUCT, CF_SCREENFONTS,
+};
+
+use super::*;
+
+pub fn font_picker(p: &FontPicker<'_>) -> Option<FontValue> {
+	let initial = utils::parse_font(p.value);
+
+	let mut logfont = LOGFONTW::default();
+	logfont.lfWeight = i32::from(initial.weight);
+	logfont.lfItalic = u8::from(initial.style != FontStyle::Normal);
+	if initial.size > 0.0 {
+		// Negative height selects by character height, assume a 96 DPI screen.
+		logfont.lfHeight = -(initial.size * 96.0 / 72.0).round() as i32;
+	}
+	let face_name_len = logfont.lfFaceName.len() - 1;
+	for (dest, src) in logfont.lfFaceName[..face_name_len].iter_mut().zip(initial.family.encode_utf16()) {
+		*dest = src;
+	}
+
+	let mut picker = CHOOSEFONTW::default();
+	picker.lStructSize = std::mem::size_of::<CHOOSEFONTW>() as u32;
+	picker.hwndOwner = hwnd(p.owner).unwrap_or_default();
+	picker.lpLogFont = &mut logfont;
+	picker.Flags = CF_SCREENFONTS | CF_INITTOLOGFONTSTRUCT;
+
+	let ok = unsafe { ChooseFontW(&mut picker).as_bool() };
+	if !ok {
+		return None;
+	}
+
+	let family_len = logfont.lfFaceName.iter().position(|&c| c == 0).unwrap_or(logfont.lfFaceName.len());
+	Some(FontValue {
+		family: String::from_utf16_lossy(&logfont.lfFaceName[..family_len]),
+		style: if logfont.lfItalic != 0 { FontStyle::Italic } else { FontStyle::Normal },
+		weight: if logfont.lfWeight == 0 { 400 } else { logfont.lfWeight.clamp(100, 1000) as u16 },
+		size: picker.iPointSize as f32 / 10.0,
+	})
+}
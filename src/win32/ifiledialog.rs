@@ -5,7 +5,7 @@ use windows::Win32::System::Com::{
 use windows::Win32::UI::Shell::{
 	FileOpenDialog, FileSaveDialog, IFileDialog, IFileOpenDialog, IFileSaveDialog, IShellItem,
 	IShellItemArray, SHCreateItemFromParsingName, SIGDN_FILESYSPATH, FILEOPENDIALOGOPTIONS,
-	FOS_ALLOWMULTISELECT, FOS_FILEMUSTEXIST, FOS_FORCEFILESYSTEM, FOS_OVERWRITEPROMPT,
+	FOS_ALLOWMULTISELECT, FOS_FILEMUSTEXIST, FOS_FORCEFILESYSTEM, FOS_FORCESHOWHIDDEN, FOS_OVERWRITEPROMPT,
 	FOS_PATHMUSTEXIST, FOS_PICKFOLDERS,
 };
 use windows::Win32::UI::Shell::Common::COMDLG_FILTERSPEC;
@@ -13,40 +13,46 @@ use windows::Win32::UI::Shell::Common::COMDLG_FILTERSPEC;
 use super::*;
 
 #[expect(dead_code)]
-pub fn pick_file(p: &FileDialog<'_>) -> Option<PathBuf> {
+pub fn pick_file(p: &FileDialog<'_>) -> Option<(PathBuf, FileSelection)> {
 	let _com = com::Apartment::init().ok()?;
 	let file_open_dialog: IFileOpenDialog = unsafe {
 		CoCreateInstance(&FileOpenDialog, None, CLSCTX_INPROC_SERVER).ok()?
 	};
 	let file_dialog: IFileDialog = file_open_dialog.cast().ok()?;
-	show_dialog(&file_dialog, p.title, p.path, p.filters, p.owner, FOS_FORCEFILESYSTEM | FOS_PATHMUSTEXIST | FOS_FILEMUSTEXIST)?;
+	show_dialog(&file_dialog, p, true, FOS_FORCEFILESYSTEM | FOS_PATHMUSTEXIST | FOS_FILEMUSTEXIST)?;
 	let item = unsafe { file_open_dialog.GetResult() }.ok()?;
-	path_from_shell_item(&item)
+	let path = path_from_shell_item(&item)?;
+	Some((path, utils::file_selection(p, selected_filter(&file_dialog, p))))
 }
 
 #[expect(dead_code)]
-pub fn pick_files(p: &FileDialog<'_>) -> Option<Vec<PathBuf>> {
+pub fn pick_files(p: &FileDialog<'_>) -> Option<(Vec<PathBuf>, FileSelection)> {
 	let _com = com::Apartment::init().ok()?;
 	let file_open_dialog: IFileOpenDialog = unsafe {
 		CoCreateInstance(&FileOpenDialog, None, CLSCTX_INPROC_SERVER).ok()?
 	};
 	let file_dialog: IFileDialog = file_open_dialog.cast().ok()?;
-	show_dialog(&file_dialog, p.title, p.path, p.filters, p.owner, FOS_FORCEFILESYSTEM | FOS_PATHMUSTEXIST | FOS_FILEMUSTEXIST | FOS_ALLOWMULTISELECT)?;
+	show_dialog(&file_dialog, p, true, FOS_FORCEFILESYSTEM | FOS_PATHMUSTEXIST | FOS_FILEMUSTEXIST | FOS_ALLOWMULTISELECT)?;
 	let items = unsafe { file_open_dialog.GetResults() }.ok()?;
 	let paths = paths_from_shell_item_array(&items);
-	if paths.is_empty() { None } else { Some(paths) }
+	if paths.is_empty() { None } else { Some((paths, utils::file_selection(p, selected_filter(&file_dialog, p)))) }
 }
 
 #[expect(dead_code)]
-pub fn save_file(p: &FileDialog<'_>) -> Option<PathBuf> {
+pub fn save_file(p: &FileDialog<'_>) -> Option<(PathBuf, FileSelection)> {
 	let _com = com::Apartment::init().ok()?;
 	let file_save_dialog: IFileSaveDialog = unsafe {
 		CoCreateInstance(&FileSaveDialog, None, CLSCTX_INPROC_SERVER).ok()?
 	};
 	let file_dialog: IFileDialog = file_save_dialog.cast().ok()?;
-	show_dialog(&file_dialog, p.title, p.path, p.filters, p.owner, FOS_FORCEFILESYSTEM | FOS_PATHMUSTEXIST | FOS_OVERWRITEPROMPT)?;
+	if let Some(default_extension) = p.default_extension {
+		let default_extension = utf16cs(default_extension);
+		unsafe { file_dialog.SetDefaultExtension(PCWSTR(default_extension.as_ptr())) }.ok()?;
+	}
+	show_dialog(&file_dialog, p, true, FOS_FORCEFILESYSTEM | FOS_PATHMUSTEXIST | FOS_OVERWRITEPROMPT)?;
 	let item = unsafe { file_save_dialog.GetResult() }.ok()?;
-	path_from_shell_item(&item)
+	let path = path_from_shell_item(&item)?;
+	Some((path, utils::file_selection(p, selected_filter(&file_dialog, p))))
 }
 
 #[expect(dead_code)]
@@ -56,7 +62,7 @@ pub fn choose_folder(p: &FileDialog<'_>) -> Option<PathBuf> {
 		CoCreateInstance(&FileOpenDialog, None, CLSCTX_INPROC_SERVER).ok()?
 	};
 	let file_dialog: IFileDialog = file_open_dialog.cast().ok()?;
-	show_dialog(&file_dialog, p.title, p.path, None, p.owner, FOS_PICKFOLDERS | FOS_FORCEFILESYSTEM | FOS_PATHMUSTEXIST)?;
+	show_dialog(&file_dialog, p, false, FOS_PICKFOLDERS | FOS_FORCEFILESYSTEM | FOS_PATHMUSTEXIST)?;
 	let item = unsafe { file_open_dialog.GetResult() }.ok()?;
 	path_from_shell_item(&item)
 }
@@ -67,7 +73,7 @@ pub fn choose_folders(p: &FileDialog<'_>) -> Option<Vec<PathBuf>> {
 		CoCreateInstance(&FileOpenDialog, None, CLSCTX_INPROC_SERVER).ok()?
 	};
 	let file_dialog: IFileDialog = file_open_dialog.cast().ok()?;
-	show_dialog(&file_dialog, p.title, p.path, None, p.owner, FOS_PICKFOLDERS | FOS_FORCEFILESYSTEM | FOS_PATHMUSTEXIST | FOS_ALLOWMULTISELECT)?;
+	show_dialog(&file_dialog, p, false, FOS_PICKFOLDERS | FOS_FORCEFILESYSTEM | FOS_PATHMUSTEXIST | FOS_ALLOWMULTISELECT)?;
 	let items = unsafe { file_open_dialog.GetResults() }.ok()?;
 	let paths = paths_from_shell_item_array(&items);
 	if paths.is_empty() { None } else { Some(paths) }
@@ -75,27 +81,44 @@ pub fn choose_folders(p: &FileDialog<'_>) -> Option<Vec<PathBuf>> {
 
 fn show_dialog(
 	dialog: &IFileDialog,
-	title: &str,
-	path: Option<&Path>,
-	filters: Option<&[FileFilter<'_>]>,
-	owner: Option<&dyn HasWindowHandle>,
+	p: &FileDialog<'_>,
+	filters: bool,
 	flags: FILEOPENDIALOGOPTIONS,
 ) -> Option<()> {
-	if !title.is_empty() {
-		let title = utf16cs(title);
+	if !p.title.is_empty() {
+		let title = utf16cs(p.title);
 		unsafe { dialog.SetTitle(PCWSTR(title.as_ptr())) }.ok()?;
 	}
 
-	if let Some(filters) = filters {
-		let filters = build_windows_filter(filters);
-		unsafe { dialog.SetFileTypes(&filters.specs) }.ok()?;
+	// The cancel button label cannot be changed.
+	if let Some(accept_label) = p.accept_label {
+		let accept_label = utf16cs(accept_label);
+		unsafe { dialog.SetOkButtonLabel(PCWSTR(accept_label.as_ptr())) }.ok()?;
+	}
+
+	if filters {
+		let filters = build_windows_filter(&utils::dialog_filters(p));
+		if !filters.specs.is_empty() {
+			unsafe { dialog.SetFileTypes(&filters.specs) }.ok()?;
+		}
+		if let Some(default) = utils::default_filter(p) {
+			// The file type index is one-based.
+			unsafe { dialog.SetFileTypeIndex(default as u32 + 1) }.ok()?;
+		}
 	}
 
 	let mut options = unsafe { dialog.GetOptions() }.ok()?;
 	options |= flags;
+	if p.show_hidden {
+		options |= FOS_FORCESHOWHIDDEN;
+	}
+	// Save dialogs prompt by default.
+	if !p.confirm_overwrite {
+		options &= !FOS_OVERWRITEPROMPT;
+	}
 	unsafe { dialog.SetOptions(options) }.ok()?;
 
-	if let Some(initial_path) = utils::abspath(path) {
+	if let Some(initial_path) = utils::abspath(p.path) {
 		if initial_path.is_dir() {
 			set_default_folder(dialog, initial_path.as_ref())?;
 		}
@@ -112,7 +135,12 @@ fn show_dialog(
 		}
 	}
 
-	unsafe { dialog.Show(hwnd(owner)) }.ok()
+	unsafe { dialog.Show(hwnd(p.owner)) }.ok()
+}
+
+fn selected_filter(dialog: &IFileDialog, p: &FileDialog<'_>) -> Option<usize> {
+	let index = unsafe { dialog.GetFileTypeIndex() }.ok()?;
+	utils::filter_index(p, (index as usize).checked_sub(1)?)
 }
 
 fn shell_item_from_path(path: &[u16]) -> Option<IShellItem> {
@@ -168,20 +196,14 @@ struct DialogFilters {
 }
 
 fn build_windows_filter(filters: &[FileFilter<'_>]) -> DialogFilters {
-	let mut names = Vec::with_capacity(filters.len() + 1);
-	let mut specs_storage = Vec::with_capacity(filters.len() + 1);
+	let mut names = Vec::with_capacity(filters.len());
+	let mut specs_storage = Vec::with_capacity(filters.len());
 
 	for entry in filters {
 		names.push(utf16cs(entry.name));
-		specs_storage.push(utf16cs(&utils::PrintJoin {
-			parts: entry.patterns,
-			separator: ";",
-		}.to_string()));
+		specs_storage.push(utf16cs(&filter_patterns(entry)));
 	}
 
-	names.push(utf16cs("All Files"));
-	specs_storage.push(utf16cs("*.*"));
-
 	let mut specs = Vec::with_capacity(names.len());
 	for index in 0..names.len() {
 		specs.push(COMDLG_FILTERSPEC {
@@ -1,15 +1,20 @@
 use raw_window_handle::{HasWindowHandle, RawWindowHandle};
+use windows::core::PCWSTR;
 use windows::Win32::Foundation::HWND;
+use windows::Win32::System::Registry::{RegGetValueW, HKEY_CLASSES_ROOT, RRF_RT_REG_SZ};
 
 use super::*;
 
+mod appearance;
 mod com;
 mod file;
 mod folder;
 mod ifiledialog;
 mod input;
 mod message;
+mod open_with;
 mod color;
+mod font;
 #[cfg(feature = "winrt-toast")]
 mod toast;
 #[cfg(not(feature = "winrt-toast"))]
@@ -22,6 +27,45 @@ fn utf16cs(value: &str) -> Vec<u16> {
 	encoded
 }
 
+// The patterns of a filter with its MIME types translated to the registered file extensions, separated by `;`.
+fn filter_patterns(filter: &FileFilter<'_>) -> String {
+	let mut patterns = utils::glob_patterns(filter.patterns.iter().copied(), utils::GlobSyntax::CaseInsensitive);
+	for &mime_type in filter.mime_types {
+		if let Some(extension) = mime_extension(mime_type) {
+			let pattern = format!("*{extension}");
+			if !patterns.contains(&pattern) {
+				patterns.push(pattern);
+			}
+		}
+	}
+	patterns.join(";")
+}
+
+// Looks up the file extension registered for a MIME type, including the leading dot.
+fn mime_extension(mime_type: &str) -> Option<String> {
+	let key = utf16cs(&format!(r"MIME\Database\Content Type\{mime_type}"));
+	let value = utf16cs("Extension");
+	let mut data = [0u16; 260];
+	let mut size = std::mem::size_of_val(&data) as u32;
+	let result = unsafe {
+		RegGetValueW(
+			HKEY_CLASSES_ROOT,
+			PCWSTR(key.as_ptr()),
+			PCWSTR(value.as_ptr()),
+			RRF_RT_REG_SZ,
+			None,
+			Some(data.as_mut_ptr() as *mut core::ffi::c_void),
+			Some(&mut size),
+		)
+	};
+	if result.is_err() {
+		return None;
+	}
+	let length = data.iter().position(|&chr| chr == 0).unwrap_or(data.len());
+	let extension = String::from_utf16_lossy(&data[..length]);
+	extension.starts_with('.').then_some(extension)
+}
+
 fn hwnd(owner: Option<&dyn HasWindowHandle>) -> Option<HWND> {
 	let raw = owner.and_then(|w| w.window_handle().ok()).map(|h| h.as_raw());
 	match raw {
@@ -36,23 +80,43 @@ pub fn message_box(p: &MessageBox<'_>) -> Option<MessageResult> {
 }
 
 #[inline]
-pub fn pick_file(p: &FileDialog<'_>) -> Option<PathBuf> {
+pub fn pick_file(p: &FileDialog<'_>) -> Option<(PathBuf, FileSelection)> {
 	// ifiledialog::pick_file(p)
 	file::pick_file(p)
 }
 
 #[inline]
-pub fn pick_files(p: &FileDialog<'_>) -> Option<Vec<PathBuf>> {
+pub fn pick_files(p: &FileDialog<'_>) -> Option<(Vec<PathBuf>, FileSelection)> {
 	// ifiledialog::pick_files(p)
 	file::pick_files(p)
 }
 
 #[inline]
-pub fn save_file(p: &FileDialog<'_>) -> Option<PathBuf> {
+pub fn save_file(p: &FileDialog<'_>) -> Option<(PathBuf, FileSelection)> {
 	// ifiledialog::save_file(p)
 	file::save_file(p)
 }
 
+#[inline]
+pub fn save_files(p: &FileDialog<'_>, names: &[&str]) -> Option<Vec<PathBuf>> {
+	utils::save_files(p, names)
+}
+
+#[inline]
+pub fn pick_uri(p: &FileDialog<'_>) -> Option<String> {
+	utils::pick_uri(p, pick_file)
+}
+
+#[inline]
+pub fn pick_uris(p: &FileDialog<'_>) -> Option<Vec<String>> {
+	utils::pick_uris(p, pick_files)
+}
+
+#[inline]
+pub fn save_uri(p: &FileDialog<'_>) -> Option<String> {
+	utils::save_uri(p)
+}
+
 #[inline]
 pub fn choose_folder(p: &FileDialog<'_>) -> Option<PathBuf> {
 	// ifiledialog::choose_folder(p)
@@ -69,11 +133,71 @@ pub fn text_input(p: &TextInput<'_>) -> Option<String> {
 	input::text_input(p)
 }
 
+#[inline]
+pub fn about_dialog(p: &AboutDialog<'_>) {
+	utils::about_dialog(p, message_box)
+}
+
+#[inline]
+pub fn text_viewer(p: &TextViewer<'_>) -> bool {
+	utils::text_viewer(p, message_box)
+}
+
+#[inline]
+pub fn login_dialog(p: &LoginDialog<'_>) -> Option<Credentials> {
+	utils::login_dialog(p, text_input)
+}
+
 #[inline]
 pub fn color_picker(p: &ColorPicker<'_>) -> Option<ColorValue> {
 	color::color_picker(p)
 }
 
+#[inline]
+pub fn pick_color_from_screen(_p: &ColorPicker<'_>) -> Option<ColorValue> {
+	None
+}
+
+#[inline]
+pub fn font_picker(p: &FontPicker<'_>) -> Option<FontValue> {
+	font::font_picker(p)
+}
+
+#[inline]
+pub fn print_dialog(_p: &PrintDialog<'_>) -> Option<PrintSettings> {
+	None
+}
+
+#[inline]
+pub fn print_file(_settings: &PrintSettings, _title: &str, _path: &Path) -> bool {
+	false
+}
+
+#[inline]
+pub fn app_chooser(_p: &AppChooser<'_>) -> Option<String> {
+	// The shell "Open with" dialog launches the application itself and does not tell which one was chosen.
+	None
+}
+
+#[inline]
+pub fn app_chooser_open(p: &AppChooser<'_>) -> bool {
+	open_with::app_chooser_open(p)
+}
+
+#[inline]
+pub fn icon_picker(p: &IconPicker<'_>) -> Option<IconValue> {
+	// No themed icons, ask for an image file instead.
+	utils::pick_icon_file(p.title, p.owner)
+}
+
+#[inline]
+pub fn desktop_appearance() -> Appearance {
+	appearance::appearance()
+}
+
+// No native log window yet, all methods do nothing.
+pub use crate::utils::NoLogWindow as LogWindowImpl;
+
 #[inline]
 pub fn notify_setup(app_id: &str) -> bool {
 	#[cfg(feature = "winrt-toast")] {
@@ -0,0 +1,21 @@
+use windows::core::PCWSTR;
+use windows::Win32::UI::Shell::{
+	SHOpenWithDialog, OAIF_ALLOW_REGISTRATION, OAIF_EXEC, OPENASINFO,
+};
+
+use super::*;
+
+pub fn app_chooser_open(p: &AppChooser<'_>) -> bool {
+	let Some(path) = utils::abspath(Some(p.path)) else {
+		return false;
+	};
+	let file = utf16cs(&path.to_string_lossy());
+
+	let info = OPENASINFO {
+		pcszFile: PCWSTR(file.as_ptr()),
+		pcszClass: PCWSTR::null(),
+		oaifInFlags: OAIF_ALLOW_REGISTRATION | OAIF_EXEC,
+	};
+
+	unsafe { SHOpenWithDialog(hwnd(p.owner), &info).is_ok() }
+}